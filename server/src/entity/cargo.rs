@@ -0,0 +1,127 @@
+use spacetimedb::{table, ReducerContext, Table, Timestamp};
+use crate::{
+    entity::cow::{mass_to_cow_size, place_cow, queue_cow_respawn, CowKind},
+    entity::entity::entity,
+    entity::ufo::{mass_to_ufo_size, ufo},
+    system::achievement::{emit_event, GameEvent},
+    system::map::{drop_off_zone, DropOffZone},
+    system::score::award_cow_points,
+    system::stats::record_mass,
+    system::system::get_config,
+    util::constants::{CARGO_BASE_CAPACITY, CARGO_MASS_PER_SLOT},
+    util::math::DbVector2,
+};
+
+// A cow sitting in a UFO's hold. It only turns into mass and points once the UFO drops it off
+// at one of the map's drop-off zones.
+#[table(name = cargo, public)]
+#[derive(Debug, Clone)]
+pub struct Cargo {
+    #[primary_key]
+    #[auto_inc]
+    pub cargo_id: u64,
+    #[index(btree)]
+    pub ufo_entity_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub kind: CowKind,
+    pub mass: u32,
+    pub stored_at: Timestamp,
+}
+
+// Bigger UFOs have room for more cows
+pub fn cargo_capacity(ufo_mass: u32) -> u32 {
+    CARGO_BASE_CAPACITY + ufo_mass / CARGO_MASS_PER_SLOT
+}
+
+pub fn cargo_count(ctx: &ReducerContext, ufo_entity_id: u32) -> u32 {
+    ctx.db.cargo().ufo_entity_id().filter(ufo_entity_id).count() as u32
+}
+
+// Weight of everything in the hold, which slows the UFO down until it's delivered
+pub fn cargo_mass(ctx: &ReducerContext, ufo_entity_id: u32) -> u32 {
+    ctx.db.cargo().ufo_entity_id().filter(ufo_entity_id).map(|cargo| cargo.mass).sum()
+}
+
+pub fn is_hold_full(ctx: &ReducerContext, ufo_entity_id: u32, ufo_mass: u32) -> bool {
+    cargo_count(ctx, ufo_entity_id) >= cargo_capacity(ufo_mass)
+}
+
+pub fn store_cow(
+    ctx: &ReducerContext,
+    ufo_entity_id: u32,
+    arena_id: u32,
+    kind: CowKind,
+    mass: u32,
+) -> Result<(), String> {
+    ctx.db.cargo().try_insert(Cargo {
+        cargo_id: 0,
+        ufo_entity_id,
+        arena_id,
+        kind,
+        mass,
+        stored_at: ctx.timestamp,
+    })?;
+    Ok(())
+}
+
+// UFOs over a drop-off zone unload their hold, banking every cow as mass and points for the
+// UFO's owner
+pub fn bank_cargo(ctx: &ReducerContext, arena_id: u32) {
+    let zones: Vec<DropOffZone> = ctx.db.drop_off_zone().arena_id().filter(arena_id).collect();
+    if zones.is_empty() {
+        return;
+    }
+    for ufo in ctx.db.ufo().arena_id().filter(arena_id) {
+        let Some(mut ufo_entity) = ctx.db.entity().entity_id().find(ufo.entity_id) else {
+            continue;
+        };
+        let ufo_pos = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+        let in_zone = zones.iter().any(|zone| {
+            (DbVector2::new(zone.position.x, zone.position.z) - ufo_pos).magnitude() <= zone.radius
+        });
+        if !in_zone {
+            continue;
+        }
+
+        let held: Vec<Cargo> = ctx.db.cargo().ufo_entity_id().filter(ufo.entity_id).collect();
+        if held.is_empty() {
+            continue;
+        }
+        log::info!("UFO {} banked {} cows", ufo.entity_id, held.len());
+        for cargo in held {
+            ufo_entity.mass += cargo.mass;
+            award_cow_points(ctx, ufo.player_id, cargo.kind.points(cargo.mass));
+            ctx.db.cargo().cargo_id().delete(cargo.cargo_id);
+            // Only a banked cow is gone for good
+            queue_cow_respawn(ctx, arena_id);
+        }
+        record_mass(ctx, ufo.player_id, ufo_entity.mass);
+        emit_event(ctx, ufo.player_id, GameEvent::MassReached(ufo_entity.mass));
+        ctx.db.entity().entity_id().update(ufo_entity);
+    }
+}
+
+// Drops everything in the UFO's hold back onto the ground around it
+pub fn drop_cargo(ctx: &ReducerContext, ufo_entity_id: u32) -> Result<(), String> {
+    let Some(ufo_entity) = ctx.db.entity().entity_id().find(ufo_entity_id) else {
+        ctx.db.cargo().ufo_entity_id().delete(ufo_entity_id);
+        return Ok(());
+    };
+    let bounds = get_config(ctx, ufo_entity.arena_id)?.bounds;
+    let center = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+    let spread = mass_to_ufo_size(ufo_entity.mass);
+    let cargo: Vec<Cargo> = ctx.db.cargo().ufo_entity_id().filter(ufo_entity_id).collect();
+    let count = cargo.len();
+    for (index, cargo) in cargo.into_iter().enumerate() {
+        // Spill the cows in a ring under the UFO
+        let angle = std::f32::consts::TAU * index as f32 / count as f32;
+        let position = bounds.constrain_circle(
+            center + DbVector2::new(angle.cos(), angle.sin()) * spread,
+            mass_to_cow_size(cargo.mass),
+        );
+        place_cow(ctx, cargo.arena_id, cargo.kind, cargo.mass, position)?;
+        ctx.db.cargo().cargo_id().delete(cargo.cargo_id);
+    }
+    Ok(())
+}
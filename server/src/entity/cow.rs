@@ -1,23 +1,69 @@
-use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table};
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp};
 use spacetimedb::rand::Rng;
 use spacetimedb::table;
+use std::collections::HashMap;
+use std::time::Duration;
 use crate::{
+    entity::cargo::cargo,
     entity::entity::{entity, Entity},
-    system::player::player,
-    system::system::config,
-    util::constants::{COW_MASS_MAX, COW_MASS_MIN, TARGET_COW_COUNT},
-    util::math::DbVector3,
-    util::util::mass_to_max_move_speed,
+    entity::obstacle::{obstacle, resolve_obstacle_collisions, steer_around, Obstacle},
+    entity::ufo::{mass_to_ufo_size, ufo},
+    system::map::{pasture, Pasture},
+    system::safe_zone::safe_zone,
+    system::system::get_config,
+    util::constants::{
+        COWS_PER_UFO, COW_MASS_MAX, COW_MASS_MIN, COW_POINTS_PER_MASS, COW_RESPAWN_DELAY_MS,
+        COW_SEPARATION_STRENGTH, COW_SPAWN_ATTEMPTS, COW_SPAWN_BEAM_CLEARANCE, GOLDEN_COW_CHANCE, GOLDEN_COW_POINTS_MULTIPLIER,
+        MAX_COWS_SPAWNED_PER_TICK, MAX_COW_COUNT, MIN_COW_COUNT,
+    },
+    util::bounds::WorldBounds,
+    util::math::{DbVector2, DbVector3},
+    util::util::{altitude_to_beam_radius, is_cow_in_beam, mass_to_max_move_speed},
 };
 
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum CowKind {
+    Regular,
+    // Rare and worth a lot more points
+    Golden,
+}
+
+impl CowKind {
+    // Points for abducting a cow of this kind
+    pub fn points(&self, mass: u32) -> u32 {
+        let points = mass * COW_POINTS_PER_MASS;
+        match self {
+            CowKind::Regular => points,
+            CowKind::Golden => points * GOLDEN_COW_POINTS_MULTIPLIER,
+        }
+    }
+}
+
 #[table(name = cow, public)]
 pub struct Cow {
     #[primary_key]
     pub entity_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub kind: CowKind,
     pub direction: DbVector3,
     pub speed: f32,
     pub is_being_abducted: bool,
-    pub abducted_by: Option<Entity>
+    pub abducted_by: Option<Entity>,
+    // Every UFO beaming the cow when more than one is, strongest pull first. The cow drifts
+    // towards the first one and isn't lifted until only one beam is left.
+    pub contested_by: Vec<u32>,
+}
+
+// A cow that was removed from the world and may be replaced once `respawn_at` has passed
+#[table(name = cow_respawn)]
+pub struct CowRespawn {
+    #[primary_key]
+    #[auto_inc]
+    pub respawn_id: u64,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub respawn_at: Timestamp,
 }
 
 // Timers
@@ -26,7 +72,8 @@ pub struct SpawnCowsTimer {
     #[primary_key]
     #[auto_inc]
     pub scheduled_id: u64,
-    pub scheduled_at: ScheduleAt
+    pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
 }
 
 #[table(name = move_all_cows_timer, scheduled(move_all_cows))]
@@ -34,7 +81,8 @@ pub struct MoveAllCowsTimer {
     #[primary_key]
     #[auto_inc]
     pub scheduled_id: u64,
-    pub scheduled_at: ScheduleAt
+    pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
 }
 
 #[table(name = change_cow_direction_timer, scheduled(change_cow_directions))]
@@ -42,15 +90,16 @@ pub struct ChangeCowDirectionTimer {
     #[primary_key]
     #[auto_inc]
     pub scheduled_id: u64,
-    pub scheduled_at: ScheduleAt
+    pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
 }
 
 // Reducers
 #[reducer]
-pub fn change_cow_directions(ctx: &ReducerContext, _timer: ChangeCowDirectionTimer) -> Result<(), String> {
-    for mut cow in ctx.db.cow().iter() {
-        let entity = ctx.db.entity().entity_id().find(&cow.entity_id);
-        if !entity.is_some() || cow.is_being_abducted {
+pub fn change_cow_directions(ctx: &ReducerContext, timer: ChangeCowDirectionTimer) -> Result<(), String> {
+    for mut cow in ctx.db.cow().arena_id().filter(timer.arena_id) {
+        let entity = ctx.db.entity().entity_id().find(cow.entity_id);
+        if entity.is_none() || cow.is_being_abducted {
             continue;
         }
         let entity = entity.unwrap();
@@ -69,65 +118,191 @@ pub fn change_cow_directions(ctx: &ReducerContext, _timer: ChangeCowDirectionTim
 }
 
 #[reducer]
-pub fn move_all_cows(ctx: &ReducerContext, _timer: MoveAllCowsTimer) -> Result<(), String> {
-    let world_size = ctx
-        .db
-        .config()
-        .id()
-        .find(0)
-        .ok_or("Config not found")?
-        .world_size;
-
-    for cow in ctx.db.cow().iter() {
-        let cow_entity = ctx.db.entity().entity_id().find(&cow.entity_id);
-        if !cow_entity.is_some() || cow.is_being_abducted {
+pub fn move_all_cows(ctx: &ReducerContext, timer: MoveAllCowsTimer) -> Result<(), String> {
+    let bounds = get_config(ctx, timer.arena_id)?.bounds;
+
+    let obstacles: Vec<Obstacle> = ctx.db.obstacle().arena_id().filter(timer.arena_id).collect();
+
+    let mut herd: Vec<Entity> = Vec::new();
+    for mut cow in ctx.db.cow().arena_id().filter(timer.arena_id) {
+        let cow_entity = ctx.db.entity().entity_id().find(cow.entity_id);
+        if cow_entity.is_none() || cow.is_being_abducted {
             continue;
         }
         let mut cow_entity = cow_entity.unwrap();
         let direction = cow.direction * cow.speed / 60.0;
         let new_pos = cow_entity.position + direction * mass_to_max_move_speed(cow_entity.mass);
         let size = mass_to_cow_size(cow_entity.mass);
-        let max = world_size as f32 - size;
-        cow_entity.position.x = new_pos.x.clamp(size, max);
-        cow_entity.position.z = new_pos.z.clamp(size, max);
+        let mut ground_pos = bounds.constrain_circle(DbVector2::new(new_pos.x, new_pos.z), size);
+
+        // Walk around anything in the way rather than into it
+        if let Some(normal) = resolve_obstacle_collisions(&obstacles, &mut ground_pos, size) {
+            let heading = steer_around(DbVector2::new(cow.direction.x, cow.direction.z), normal);
+            cow.direction = DbVector3::new(heading.x, 0.0, heading.y);
+            ctx.db.cow().entity_id().update(cow);
+        }
+
+        let ground_pos = bounds.constrain_circle(ground_pos, size);
+        cow_entity.position.x = ground_pos.x;
+        cow_entity.position.z = ground_pos.y;
+        herd.push(cow_entity);
+    }
+
+    separate_cows(&mut herd);
+    for mut cow_entity in herd {
+        let size = mass_to_cow_size(cow_entity.mass);
+        let mut ground_pos = DbVector2::new(cow_entity.position.x, cow_entity.position.z);
+        resolve_obstacle_collisions(&obstacles, &mut ground_pos, size);
+        let ground_pos = bounds.constrain_circle(ground_pos, size);
+        cow_entity.position.x = ground_pos.x;
+        cow_entity.position.z = ground_pos.y;
         ctx.db.entity().entity_id().update(cow_entity);
     }
     Ok(())
 }
 
 #[reducer]
-pub fn spawn_cows(ctx: &ReducerContext, _timer: SpawnCowsTimer) -> Result<(), String> {
-    if ctx.db.player().count() == 0 {
+pub fn spawn_cows(ctx: &ReducerContext, timer: SpawnCowsTimer) -> Result<(), String> {
+    let arena_id = timer.arena_id;
+    // Only UFOs that are actually in the game count towards the herd size
+    let ufo_count = ctx.db.ufo().arena_id().filter(arena_id).count();
+    if ufo_count == 0 {
         return Ok(());
     }
 
-    let world_size = ctx
-        .db
-        .config()
-        .id()
-        .find(0)
-        .ok_or("Config not found")?
-        .world_size;
-    
-    let mut cow_count = ctx.db.cow().count();
-
-    while cow_count < TARGET_COW_COUNT as u64 {
-        spawn_cow(ctx, world_size)?;
-        cow_count += 1;
+    let bounds = get_config(ctx, arena_id)?.bounds;
+
+    // Cows that were removed recently hold their slot until their delay has passed
+    let mut pending_respawns = 0;
+    for respawn in ctx.db.cow_respawn().arena_id().filter(arena_id) {
+        if respawn.respawn_at <= ctx.timestamp {
+            ctx.db.cow_respawn().respawn_id().delete(respawn.respawn_id);
+        } else {
+            pending_respawns += 1;
+        }
+    }
+
+    // Cows in a hold still count, since they go back on the ground if the UFO drops them
+    let cow_count =
+        ctx.db.cow().arena_id().filter(arena_id).count() + ctx.db.cargo().arena_id().filter(arena_id).count();
+    let to_spawn = target_cow_count(ufo_count)
+        .saturating_sub(cow_count)
+        .saturating_sub(pending_respawns)
+        .min(MAX_COWS_SPAWNED_PER_TICK);
+
+    for _ in 0..to_spawn {
+        spawn_cow(ctx, arena_id, &bounds)?;
     }
     Ok(())
 }
 
-pub fn spawn_cow(ctx: &ReducerContext, world_size: u64) -> Result<(), String> {
+// Nudges overlapping cows apart. Cows are bucketed into a grid of cells as wide as the biggest
+// cow, so each one is only checked against cows in its own and neighboring cells.
+fn separate_cows(herd: &mut [Entity]) {
+    let cell_size = mass_to_cow_size(COW_MASS_MAX) * 2.0;
+    let cell_of = |entity: &Entity| {
+        (
+            (entity.position.x / cell_size).floor() as i32,
+            (entity.position.z / cell_size).floor() as i32,
+        )
+    };
+    let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+    for (index, cow_entity) in herd.iter().enumerate() {
+        grid.entry(cell_of(cow_entity)).or_default().push(index);
+    }
+
+    let mut pushes = vec![DbVector2::new(0.0, 0.0); herd.len()];
+    for (index, cow_entity) in herd.iter().enumerate() {
+        let (cell_x, cell_z) = cell_of(cow_entity);
+        let pos = DbVector2::new(cow_entity.position.x, cow_entity.position.z);
+        let size = mass_to_cow_size(cow_entity.mass);
+        for neighbor_cell in (-1..=1).flat_map(|dx| (-1..=1).map(move |dz| (cell_x + dx, cell_z + dz))) {
+            let Some(neighbors) = grid.get(&neighbor_cell) else {
+                continue;
+            };
+            // Each pair is handled once, from its lower index
+            for &other in neighbors.iter().filter(|&&other| other > index) {
+                let other_entity = &herd[other];
+                let offset = DbVector2::new(other_entity.position.x, other_entity.position.z) - pos;
+                let distance = offset.magnitude();
+                let overlap = size + mass_to_cow_size(other_entity.mass) - distance;
+                if overlap <= 0.0 {
+                    continue;
+                }
+                // Cows on the exact same spot split along an arbitrary but stable axis
+                let normal = if distance > 0.0 { offset / distance } else { DbVector2::new(1.0, 0.0) };
+                let push = normal * (overlap * COW_SEPARATION_STRENGTH / 2.0);
+                pushes[index] -= push;
+                pushes[other] += push;
+            }
+        }
+    }
+
+    for (cow_entity, push) in herd.iter_mut().zip(pushes) {
+        cow_entity.position.x += push.x;
+        cow_entity.position.z += push.y;
+    }
+}
+
+pub fn target_cow_count(ufo_count: usize) -> usize {
+    (ufo_count * COWS_PER_UFO).clamp(MIN_COW_COUNT, MAX_COW_COUNT)
+}
+
+pub fn queue_cow_respawn(ctx: &ReducerContext, arena_id: u32) {
+    ctx.db.cow_respawn().insert(CowRespawn {
+        respawn_id: 0,
+        arena_id,
+        respawn_at: ctx.timestamp + Duration::from_millis(COW_RESPAWN_DELAY_MS),
+    });
+}
+
+// Drops every cow a UFO is holding back onto the ground
+pub fn release_abducted_cows(ctx: &ReducerContext, arena_id: u32, ufo_entity_id: u32) {
+    for mut cow in ctx.db.cow().arena_id().filter(arena_id) {
+        match cow.abducted_by {
+            Some(ref ufo) if ufo.entity_id == ufo_entity_id => {
+                cow.is_being_abducted = false;
+                cow.abducted_by = None;
+                cow.contested_by.clear();
+                if let Some(mut cow_entity) = ctx.db.entity().entity_id().find(cow.entity_id) {
+                    cow_entity.position.y = 0.125f32;
+                    ctx.db.entity().entity_id().update(cow_entity);
+                }
+                ctx.db.cow().entity_id().update(cow);
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn spawn_cow(ctx: &ReducerContext, arena_id: u32, bounds: &WorldBounds) -> Result<(), String> {
     let mut rng = ctx.rng();
     let cow_mass = rng.gen_range(COW_MASS_MIN..COW_MASS_MAX);
+    let kind = if rng.gen_bool(GOLDEN_COW_CHANCE) { CowKind::Golden } else { CowKind::Regular };
     let cow_size = mass_to_cow_size(cow_mass);
 
-    let x = rng.gen_range(cow_size..world_size as f32 - cow_size);
+    let Some(spawn_pos) = find_cow_spawn_position(ctx, arena_id, bounds, cow_size) else {
+        log::debug!("No free spot to spawn a cow");
+        return Ok(());
+    };
+    place_cow(ctx, arena_id, kind, cow_mass, spawn_pos)
+}
+
+// Puts a cow on the ground at the given spot, wandering off in a random direction
+pub fn place_cow(
+    ctx: &ReducerContext,
+    arena_id: u32,
+    kind: CowKind,
+    cow_mass: u32,
+    spawn_pos: DbVector2,
+) -> Result<(), String> {
+    let mut rng = ctx.rng();
+    let x = spawn_pos.x;
     let y: f32 = 0.125f32;
-    let z = rng.gen_range(cow_size..world_size as f32 - cow_size);
+    let z = spawn_pos.y;
     let entity = ctx.db.entity().try_insert(Entity {
         entity_id: 0,
+        arena_id,
         position: DbVector3 { x, y, z },
         mass: cow_mass,
     })?;
@@ -140,14 +315,73 @@ pub fn spawn_cow(ctx: &ReducerContext, world_size: u64) -> Result<(), String> {
     };
     ctx.db.cow().try_insert(Cow {
         entity_id: entity.entity_id,
-        direction: direction,
+        arena_id,
+        kind,
+        direction,
         is_being_abducted: false,
         speed: 1.0,
-        abducted_by: None
+        abducted_by: None,
+        contested_by: Vec::new(),
     })?;
     Ok(())
 }
 
+// Picks a random point that is clear of obstacles and not inside, or right next to, any UFO's beam
+fn find_cow_spawn_position(
+    ctx: &ReducerContext,
+    arena_id: u32,
+    bounds: &WorldBounds,
+    cow_size: f32,
+) -> Option<DbVector2> {
+    let mut rng = ctx.rng();
+    let solid_obstacles: Vec<Obstacle> = ctx
+        .db
+        .obstacle()
+        .arena_id()
+        .filter(arena_id)
+        .filter(|obstacle| obstacle.solid)
+        .collect();
+    let ufos: Vec<(DbVector2, f32, f32)> = ctx
+        .db
+        .ufo()
+        .arena_id()
+        .filter(arena_id)
+        .filter_map(|ufo| ctx.db.entity().entity_id().find(ufo.entity_id))
+        .map(|ufo_entity| {
+            let ufo_pos = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+            let beam_radius = altitude_to_beam_radius(ufo_entity.position.y);
+            (ufo_pos, beam_radius, mass_to_ufo_size(ufo_entity.mass) + COW_SPAWN_BEAM_CLEARANCE)
+        })
+        .collect();
+
+    let pastures: Vec<Pasture> = ctx.db.pasture().arena_id().filter(arena_id).collect();
+    let zone = ctx.db.safe_zone().arena_id().find(arena_id);
+
+    for _ in 0..COW_SPAWN_ATTEMPTS {
+        // Cows graze in pastures when the map has any
+        let candidate = if pastures.is_empty() {
+            bounds.random_point(&mut rng, cow_size)?
+        } else {
+            let pasture = &pastures[rng.gen_range(0..pastures.len())];
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let distance = pasture.radius * rng.gen_range(0.0f32..1.0).sqrt();
+            bounds.constrain_circle(
+                DbVector2::new(pasture.position.x + angle.cos() * distance, pasture.position.z + angle.sin() * distance),
+                cow_size,
+            )
+        };
+        let near_beam = ufos.iter().any(|(ufo_pos, beam_radius, clearance)| {
+            is_cow_in_beam(candidate, *ufo_pos, *beam_radius) || (candidate - *ufo_pos).magnitude() < *clearance
+        });
+        let blocked = solid_obstacles.iter().any(|obstacle| obstacle.overlaps(candidate, cow_size))
+            || zone.as_ref().is_some_and(|zone| !zone.contains(candidate, cow_size));
+        if !near_beam && !blocked {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
 pub fn mass_to_cow_size(mass: u32) -> f32 {
     // Convert mass to size in meters
     // Assuming mass is in kg and size is in meters
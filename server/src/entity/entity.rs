@@ -6,6 +6,8 @@ pub struct Entity {
     #[auto_inc]
     #[primary_key]
     pub entity_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
     pub position: DbVector3,
     pub mass: u32,
 }
\ No newline at end of file
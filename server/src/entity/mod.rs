@@ -1,3 +1,6 @@
 pub mod cow;
 pub mod ufo;
-pub mod entity;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod entity;
+pub mod obstacle;
+pub mod cargo;
\ No newline at end of file
@@ -0,0 +1,179 @@
+use serde::Deserialize;
+use spacetimedb::{table, ReducerContext, SpacetimeType, Table};
+use crate::util::constants::OBSTACLE_SHADOW_MARGIN;
+use crate::util::math::{DbVector2, DbVector3};
+
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ObstacleKind {
+    Barn,
+    Silo,
+    Fence,
+    Tree,
+    Pond,
+}
+
+// Footprint of an obstacle on the ground plane, centered on its position
+#[derive(SpacetimeType, Debug, Clone, Copy)]
+pub enum CollisionShape {
+    Circle(f32),
+    // Axis aligned box, stored as half extents
+    Rect(DbVector2),
+}
+
+#[table(name = obstacle, public)]
+#[derive(Debug, Clone)]
+pub struct Obstacle {
+    #[primary_key]
+    #[auto_inc]
+    pub obstacle_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub kind: ObstacleKind,
+    pub position: DbVector3,
+    pub shape: CollisionShape,
+    // Cows can't walk through solid obstacles
+    pub solid: bool,
+    // Cows under or right next to this obstacle can't be beamed up
+    pub shadows_beam: bool,
+}
+
+impl ObstacleKind {
+    // Barns are open so cows can wander in and hide, the rest have to be walked around
+    pub fn is_solid(&self) -> bool {
+        !matches!(self, ObstacleKind::Barn)
+    }
+
+    pub fn shadows_beam(&self) -> bool {
+        !matches!(self, ObstacleKind::Fence | ObstacleKind::Pond)
+    }
+}
+
+impl CollisionShape {
+    fn closest_point(&self, center: DbVector2, point: DbVector2) -> DbVector2 {
+        match self {
+            CollisionShape::Circle(radius) => {
+                let offset = point - center;
+                if offset.magnitude() <= *radius {
+                    point
+                } else {
+                    center + offset.normalized() * *radius
+                }
+            }
+            CollisionShape::Rect(half) => DbVector2::new(
+                point.x.clamp(center.x - half.x, center.x + half.x),
+                point.y.clamp(center.y - half.y, center.y + half.y),
+            ),
+        }
+    }
+
+    pub fn overlaps(&self, center: DbVector2, point: DbVector2, radius: f32) -> bool {
+        (point - self.closest_point(center, point)).magnitude() < radius
+    }
+
+    // Returns the pushed out position and the surface normal if a circle overlaps this shape
+    fn push_out(&self, center: DbVector2, point: DbVector2, radius: f32) -> Option<(DbVector2, DbVector2)> {
+        match self {
+            CollisionShape::Circle(shape_radius) => {
+                let offset = point - center;
+                let distance = offset.magnitude();
+                if distance >= shape_radius + radius {
+                    return None;
+                }
+                let normal = if distance > 0.0 { offset / distance } else { DbVector2::new(1.0, 0.0) };
+                Some((center + normal * (shape_radius + radius), normal))
+            }
+            CollisionShape::Rect(half) => {
+                let closest = self.closest_point(center, point);
+                let offset = point - closest;
+                let distance = offset.magnitude();
+                if distance >= radius {
+                    return None;
+                }
+                if distance > 0.0 {
+                    let normal = offset / distance;
+                    return Some((closest + normal * radius, normal));
+                }
+                // The center is inside the box, leave through the nearest side
+                let local = point - center;
+                let depth_x = half.x - local.x.abs();
+                let depth_y = half.y - local.y.abs();
+                let normal = if depth_x < depth_y {
+                    DbVector2::new(local.x.signum(), 0.0)
+                } else {
+                    DbVector2::new(0.0, local.y.signum())
+                };
+                let exit = if depth_x < depth_y { depth_x } else { depth_y };
+                Some((point + normal * (exit + radius), normal))
+            }
+        }
+    }
+}
+
+impl Obstacle {
+    pub fn ground_position(&self) -> DbVector2 {
+        DbVector2::new(self.position.x, self.position.z)
+    }
+
+    pub fn overlaps(&self, point: DbVector2, radius: f32) -> bool {
+        self.shape.overlaps(self.ground_position(), point, radius)
+    }
+}
+
+pub fn spawn_obstacle(
+    ctx: &ReducerContext,
+    arena_id: u32,
+    kind: ObstacleKind,
+    position: DbVector2,
+    shape: CollisionShape,
+) -> Result<Obstacle, String> {
+    Ok(ctx.db.obstacle().try_insert(Obstacle {
+        obstacle_id: 0,
+        arena_id,
+        kind,
+        position: DbVector3::new(position.x, 0.0, position.y),
+        shape,
+        solid: kind.is_solid(),
+        shadows_beam: kind.shadows_beam(),
+    })?)
+}
+
+// Pushes a circle out of every solid obstacle it overlaps. Returns the combined surface normal
+// of everything it was pushed out of, if anything.
+pub fn resolve_obstacle_collisions(
+    obstacles: &[Obstacle],
+    position: &mut DbVector2,
+    radius: f32,
+) -> Option<DbVector2> {
+    // The first normal hit, and the sum of all of them
+    let mut normal: Option<(DbVector2, DbVector2)> = None;
+    for obstacle in obstacles.iter().filter(|obstacle| obstacle.solid) {
+        if let Some((pushed, hit_normal)) = obstacle.shape.push_out(obstacle.ground_position(), *position, radius) {
+            *position = pushed;
+            normal = Some(normal.map_or((hit_normal, hit_normal), |(first, sum)| (first, sum + hit_normal)));
+        }
+    }
+    // Opposing surfaces, like a cow wedged between two obstacles, cancel out
+    normal.map(|(first, sum)| if sum.sqr_magnitude() > 0.0 { sum.normalized() } else { first })
+}
+
+pub fn is_shadowed(obstacles: &[Obstacle], point: DbVector2) -> bool {
+    obstacles
+        .iter()
+        .filter(|obstacle| obstacle.shadows_beam)
+        .any(|obstacle| obstacle.overlaps(point, OBSTACLE_SHADOW_MARGIN))
+}
+
+// Turns a heading that runs into an obstacle so it slides along the obstacle's surface instead
+pub fn steer_around(direction: DbVector2, normal: DbVector2) -> DbVector2 {
+    let into_surface = direction.dot(normal);
+    if into_surface >= 0.0 {
+        return direction;
+    }
+    let tangent = direction - normal * into_surface;
+    if tangent.sqr_magnitude() > 0.0001 {
+        tangent.normalized()
+    } else {
+        DbVector2::new(-normal.y, normal.x)
+    }
+}
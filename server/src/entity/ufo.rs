@@ -1,4 +1,5 @@
 use crate::entity::entity::Entity;
+use crate::util::constants::UFO_RADIUS_PER_MASS;
 use crate::util::math::DbVector3;
 use spacetimedb::Timestamp;
 
@@ -8,14 +9,27 @@ pub struct Ufo {
     pub entity_id: u32,
     #[index(btree)]
     pub player_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
     pub direction: DbVector3,
     pub speed: f32,
+    // Ground velocity in units per second, x and z only
+    pub velocity: DbVector3,
+    // The height the UFO is climbing or descending towards
+    pub target_altitude: f32,
     pub last_split_time: Timestamp,
+    // Boosting is on cooldown for BOOST_COOLDOWN_MS after this
+    pub last_boost_time: Timestamp,
     pub beam_on: bool,
+    // Drains while the beam is on and recharges while it's off, up to MAX_BEAM_ENERGY
+    pub beam_energy: f32,
+    // Mass lost to decay or the safe zone that hasn't added up to a whole unit yet
+    pub decay_remainder: f32,
     pub abducting: bool,
     pub abducted_entity: Option<Entity>,
 }
 
+// Radius of the UFO, used for walls and collisions
 pub fn mass_to_ufo_size(mass: u32) -> f32 {
-    mass as f32 * 0.01f32
+    mass as f32 * UFO_RADIUS_PER_MASS
 }
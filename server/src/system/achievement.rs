@@ -0,0 +1,159 @@
+use spacetimedb::{reducer, table, Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+use crate::{
+    system::bot::is_bot,
+    system::player::player,
+    system::stats::{player_stats, PlayerStats},
+    system::system::{message, require_admin, Message},
+};
+
+// What an achievement counts towards its threshold
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum AchievementTrigger {
+    // Lifetime cows of any kind
+    CowsAbducted,
+    GoldenCowsAbducted,
+    ReachMass,
+    // Finish a round at this rank or better
+    FinishRoundRank,
+}
+
+// Something that happened in the simulation that might unlock an achievement
+#[derive(Debug, Clone, Copy)]
+pub enum GameEvent {
+    CowAbducted,
+    MassReached(u32),
+    RoundFinished { rank: u32 },
+}
+
+// Achievement definitions. Seeded on init, and admins can add more without republishing.
+#[table(name = achievement, public)]
+#[derive(Debug, Clone)]
+pub struct Achievement {
+    #[primary_key]
+    pub key: String,
+    pub name: String,
+    pub description: String,
+    pub trigger: AchievementTrigger,
+    pub threshold: u32,
+}
+
+#[table(name = player_achievement, public)]
+pub struct PlayerAchievement {
+    #[primary_key]
+    #[auto_inc]
+    pub unlock_id: u64,
+    #[index(btree)]
+    pub identity: Identity,
+    pub achievement_key: String,
+    pub unlocked_at: Timestamp,
+}
+
+// Reducers
+#[reducer]
+pub fn define_achievement(
+    ctx: &ReducerContext,
+    key: String,
+    name: String,
+    description: String,
+    trigger: AchievementTrigger,
+    threshold: u32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if key.is_empty() || name.is_empty() {
+        return Err("Achievements need a key and a name".to_string());
+    }
+    let achievement = Achievement {
+        key,
+        name,
+        description,
+        trigger,
+        threshold,
+    };
+    if ctx.db.achievement().key().find(&achievement.key).is_some() {
+        ctx.db.achievement().key().update(achievement);
+    } else {
+        ctx.db.achievement().try_insert(achievement)?;
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_achievement(ctx: &ReducerContext, key: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !ctx.db.achievement().key().delete(&key) {
+        return Err("Achievement not found".to_string());
+    }
+    Ok(())
+}
+
+pub fn seed_achievements(ctx: &ReducerContext) -> Result<(), String> {
+    let defaults = [
+        ("first_abduction", "First Contact", "Abduct your first cow", AchievementTrigger::CowsAbducted, 1),
+        ("hundred_cows", "Cattle Baron", "Abduct 100 cows", AchievementTrigger::CowsAbducted, 100),
+        ("golden_cow", "Struck Gold", "Abduct a golden cow", AchievementTrigger::GoldenCowsAbducted, 1),
+        ("mass_50", "Heavyweight", "Reach a mass of 50", AchievementTrigger::ReachMass, 50),
+        ("round_winner", "Top of the Herd", "Win a round", AchievementTrigger::FinishRoundRank, 1),
+    ];
+    for (key, name, description, trigger, threshold) in defaults {
+        ctx.db.achievement().try_insert(Achievement {
+            key: key.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            trigger,
+            threshold,
+        })?;
+    }
+    Ok(())
+}
+
+// Unlocks every achievement the event completes for the player and announces it in chat
+pub fn emit_event(ctx: &ReducerContext, player_id: u32, event: GameEvent) {
+    // Achievements are only for humans
+    if is_bot(ctx, player_id) {
+        return;
+    }
+    let Some(player) = ctx.db.player().player_id().find(player_id) else {
+        return;
+    };
+    let stats = ctx.db.player_stats().identity().find(player.identity);
+    let unlocked: Vec<String> = ctx
+        .db
+        .player_achievement()
+        .identity()
+        .filter(player.identity)
+        .map(|unlock| unlock.achievement_key)
+        .collect();
+
+    for achievement in ctx.db.achievement().iter() {
+        if unlocked.contains(&achievement.key) || !is_met(&achievement, event, stats.as_ref()) {
+            continue;
+        }
+        log::info!("Player {} unlocked {}", player_id, achievement.key);
+        ctx.db.player_achievement().insert(PlayerAchievement {
+            unlock_id: 0,
+            identity: player.identity,
+            achievement_key: achievement.key,
+            unlocked_at: ctx.timestamp,
+        });
+        ctx.db.message().insert(Message {
+            sender: ctx.identity(),
+            sent: ctx.timestamp,
+            text: format!("{} unlocked {}: {}", player.name, achievement.name, achievement.description),
+        });
+    }
+}
+
+fn is_met(achievement: &Achievement, event: GameEvent, stats: Option<&PlayerStats>) -> bool {
+    let threshold = achievement.threshold;
+    match (achievement.trigger, event) {
+        (AchievementTrigger::CowsAbducted, GameEvent::CowAbducted) => {
+            stats.is_some_and(|stats| stats.regular_cows_abducted + stats.golden_cows_abducted >= threshold)
+        }
+        (AchievementTrigger::GoldenCowsAbducted, GameEvent::CowAbducted) => {
+            stats.is_some_and(|stats| stats.golden_cows_abducted >= threshold)
+        }
+        (AchievementTrigger::ReachMass, GameEvent::MassReached(mass)) => mass >= threshold,
+        (AchievementTrigger::FinishRoundRank, GameEvent::RoundFinished { rank }) => rank <= threshold,
+        _ => false,
+    }
+}
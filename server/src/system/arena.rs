@@ -0,0 +1,268 @@
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, Timestamp};
+use std::time::Duration;
+use crate::{
+    entity::cow::{
+        change_cow_direction_timer, cow, cow_respawn, move_all_cows_timer, spawn_cows_timer,
+        ChangeCowDirectionTimer, MoveAllCowsTimer, SpawnCowsTimer,
+    },
+    entity::cargo::cargo,
+    entity::entity::entity,
+    entity::ufo::ufo,
+    system::map::{clear_map, embedded_map, load_map, MapDefinition, DEFAULT_MAP},
+    system::matchmaking::matchmaking_queue,
+    system::round::{start_rounds, stop_rounds},
+    system::bot::{balance_bots, remove_bots, BotDifficulty},
+    system::control_zone::stop_control_zone,
+    system::safe_zone::stop_safe_zone,
+    system::score::{clear_scores, reset_score},
+    system::stats::{end_session, start_session},
+    system::team::{assign_team, team},
+    system::player::{player, validate_name, Player},
+    system::system::{
+        config, process_game_timer, remove_player_ufos, require_admin, spawn_player, Config,
+        GameMode, ProcessGameTimer,
+    },
+    util::bounds::WorldBounds,
+    util::constants::{
+        BOT_FILL_COUNT, EMPTY_ARENA_TIMEOUT_MS, MASS_DECAY_PER_SEC, MASS_DECAY_THRESHOLD,
+        MAX_UFO_ALTITUDE, MIN_UFO_ALTITUDE, PROCESS_GAME_INTERVAL_MS, WORLD_SIZE,
+    },
+};
+
+pub const DEFAULT_ARENA_CAPACITY: u32 = 8;
+pub const DEFAULT_REGION: &str = "global";
+
+// A self-contained world. Everything in the simulation belongs to exactly one arena,
+// and each arena runs on its own set of timers.
+#[table(name = arena, public)]
+#[derive(Debug, Clone)]
+pub struct Arena {
+    #[primary_key]
+    #[auto_inc]
+    pub arena_id: u32,
+    pub name: String,
+    // Matchmaking only places players into arenas in their own region
+    pub region: String,
+    pub capacity: u32,
+    pub player_count: u32,
+    pub created_at: Timestamp,
+    // When the last player left. Arenas that stay empty for long enough are deleted.
+    pub empty_since: Option<Timestamp>,
+}
+
+// Reducers
+#[reducer]
+pub fn create_arena(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let name = validate_name(name)?;
+    create_arena_with_map(
+        ctx,
+        name,
+        DEFAULT_REGION.to_string(),
+        DEFAULT_ARENA_CAPACITY,
+        &embedded_map(DEFAULT_MAP)?,
+    )?;
+    Ok(())
+}
+
+#[reducer]
+pub fn join_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    let arena = ctx.db.arena().arena_id().find(arena_id).ok_or("Arena not found")?;
+    if player.arena_id == Some(arena_id) {
+        return Err("Already in that arena".to_string());
+    }
+    if arena.player_count >= arena.capacity {
+        return Err("Arena is full".to_string());
+    }
+    let player_id = player.player_id;
+    ctx.db.matchmaking_queue().identity().delete(ctx.sender);
+    move_player_to_arena(ctx, player, Some(arena_id))?;
+    spawn_player(ctx, player_id, arena_id)
+}
+
+#[reducer]
+pub fn leave_arena(ctx: &ReducerContext) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if player.arena_id.is_none() {
+        return Err("Not in an arena".to_string());
+    }
+    move_player_to_arena(ctx, player, None)
+}
+
+#[reducer]
+pub fn close_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    ctx.db.arena().arena_id().find(arena_id).ok_or("Arena not found")?;
+    delete_arena(ctx, arena_id)
+}
+
+pub fn create_arena_with_map(
+    ctx: &ReducerContext,
+    name: String,
+    region: String,
+    capacity: u32,
+    map: &MapDefinition,
+) -> Result<Arena, String> {
+    let arena = ctx.db.arena().try_insert(Arena {
+        arena_id: 0,
+        name,
+        region,
+        capacity,
+        player_count: 0,
+        created_at: ctx.timestamp,
+        empty_since: Some(ctx.timestamp),
+    })?;
+    let arena_id = arena.arena_id;
+    log::info!("Creating arena {} ({})", arena_id, arena.name);
+
+    ctx.db.config().try_insert(Config {
+        arena_id,
+        mode: GameMode::FreeForAll,
+        teams: false,
+        bounds: WorldBounds::square(WORLD_SIZE as f32),
+        map_name: String::new(),
+        map_seed: None,
+        mass_decay_threshold: MASS_DECAY_THRESHOLD,
+        mass_decay_per_sec: MASS_DECAY_PER_SEC,
+        min_altitude: MIN_UFO_ALTITUDE,
+        max_altitude: MAX_UFO_ALTITUDE,
+        bot_fill: BOT_FILL_COUNT,
+        bot_difficulty: BotDifficulty::Normal,
+    })?;
+    load_map(ctx, arena_id, map)?;
+
+    ctx.db.spawn_cows_timer().try_insert(SpawnCowsTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(500).into()),
+        arena_id,
+    })?;
+    ctx.db.process_game_timer().try_insert(ProcessGameTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(PROCESS_GAME_INTERVAL_MS).into()),
+        arena_id,
+    })?;
+    ctx.db
+        .change_cow_direction_timer()
+        .try_insert(ChangeCowDirectionTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_millis(1000).into()),
+            arena_id,
+        })?;
+    ctx.db.move_all_cows_timer().try_insert(MoveAllCowsTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(50).into()),
+        arena_id,
+    })?;
+    start_rounds(ctx, arena_id)?;
+
+    Ok(arena)
+}
+
+// Removes an arena along with everything in it. Players inside are sent back to the menu.
+pub fn delete_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    log::info!("Deleting arena {}", arena_id);
+    remove_bots(ctx, arena_id)?;
+    for player in ctx.db.player().iter().filter(|player| player.arena_id == Some(arena_id)) {
+        end_session(ctx, player.identity);
+        remove_player_ufos(ctx, player.player_id)?;
+        ctx.db.player().identity().update(Player { arena_id: None, team_id: None, ..player });
+    }
+
+    for timer in ctx.db.spawn_cows_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.spawn_cows_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    for timer in ctx.db.process_game_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.process_game_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    for timer in ctx.db.change_cow_direction_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.change_cow_direction_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    for timer in ctx.db.move_all_cows_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.move_all_cows_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    stop_rounds(ctx, arena_id);
+    stop_safe_zone(ctx, arena_id);
+    stop_control_zone(ctx, arena_id);
+    clear_scores(ctx, arena_id);
+
+    ctx.db.cow().arena_id().delete(arena_id);
+    ctx.db.ufo().arena_id().delete(arena_id);
+    ctx.db.entity().arena_id().delete(arena_id);
+    ctx.db.cow_respawn().arena_id().delete(arena_id);
+    clear_map(ctx, arena_id);
+    ctx.db.config().arena_id().delete(arena_id);
+    ctx.db.cargo().arena_id().delete(arena_id);
+    ctx.db.team().arena_id().delete(arena_id);
+    ctx.db.arena().arena_id().delete(arena_id);
+    Ok(())
+}
+
+// Takes the player's UFOs out of their current arena and records which arena they're in now
+pub fn move_player_to_arena(ctx: &ReducerContext, player: Player, arena_id: Option<u32>) -> Result<(), String> {
+    let previous_arena_id = player.arena_id;
+    let player_id = player.player_id;
+    let identity = player.identity;
+    remove_player_ufos(ctx, player_id)?;
+    reset_score(ctx, player_id, arena_id)?;
+    ctx.db.player().identity().update(Player { arena_id, team_id: None, ..player });
+    assign_team(ctx, player_id)?;
+
+    // Play time only counts while the player is in an arena
+    if let Some(previous_arena_id) = previous_arena_id {
+        end_session(ctx, identity);
+        refresh_player_count(ctx, previous_arena_id);
+        balance_bots(ctx, previous_arena_id)?;
+    }
+    if let Some(arena_id) = arena_id {
+        start_session(ctx, identity);
+        refresh_player_count(ctx, arena_id);
+        balance_bots(ctx, arena_id)?;
+    }
+    Ok(())
+}
+
+pub fn refresh_player_count(ctx: &ReducerContext, arena_id: u32) {
+    if let Some(mut arena) = ctx.db.arena().arena_id().find(arena_id) {
+        arena.player_count = ctx
+            .db
+            .player()
+            .iter()
+            .filter(|player| player.arena_id == Some(arena_id))
+            .count() as u32;
+        arena.empty_since = match arena.player_count {
+            0 => arena.empty_since.or(Some(ctx.timestamp)),
+            _ => None,
+        };
+        ctx.db.arena().arena_id().update(arena);
+    }
+}
+
+// Deletes arenas nobody has been in for a while, so their timers stop running
+pub fn reap_empty_arenas(ctx: &ReducerContext) -> Result<(), String> {
+    let expired: Vec<u32> = ctx
+        .db
+        .arena()
+        .iter()
+        .filter(|arena| {
+            arena
+                .empty_since
+                .is_some_and(|since| ctx.timestamp >= since + Duration::from_millis(EMPTY_ARENA_TIMEOUT_MS))
+        })
+        .map(|arena| arena.arena_id)
+        .collect();
+    for arena_id in expired {
+        delete_arena(ctx, arena_id)?;
+    }
+    Ok(())
+}
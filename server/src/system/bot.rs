@@ -0,0 +1,288 @@
+use spacetimedb::rand::Rng;
+use spacetimedb::{reducer, table, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp};
+use std::time::Duration;
+use crate::{
+    entity::cargo::is_hold_full,
+    entity::cow::cow,
+    entity::entity::entity,
+    entity::ufo::ufo,
+    system::arena::{arena, refresh_player_count},
+    system::map::drop_off_zone,
+    system::player::{player, set_beam, Player},
+    system::score::reset_score,
+    system::system::{config, get_config, remove_player_ufos, require_admin, spawn_player, Config},
+    system::team::{are_teammates, assign_team},
+    util::constants::{BOT_WANDER_TURN_CHANCE, START_SKILL_RATING},
+    util::math::{DbVector2, DbVector3},
+    util::util::altitude_to_beam_radius,
+};
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum BotDifficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl BotDifficulty {
+    // How far away the bot notices cows and other UFOs
+    fn sight_radius(self) -> f32 {
+        match self {
+            BotDifficulty::Easy => 3.0,
+            BotDifficulty::Normal => 5.0,
+            BotDifficulty::Hard => 8.0,
+        }
+    }
+
+    // Fraction of full speed the bot flies at
+    fn speed(self) -> f32 {
+        match self {
+            BotDifficulty::Easy => 0.6,
+            BotDifficulty::Normal => 0.8,
+            BotDifficulty::Hard => 1.0,
+        }
+    }
+
+    // Time between decisions
+    fn reaction_ms(self) -> u64 {
+        match self {
+            BotDifficulty::Easy => 800,
+            BotDifficulty::Normal => 400,
+            BotDifficulty::Hard => 150,
+        }
+    }
+
+    // Most the bot's heading can be off by, in radians
+    fn aim_error(self) -> f32 {
+        match self {
+            BotDifficulty::Easy => 0.6,
+            BotDifficulty::Normal => 0.3,
+            BotDifficulty::Hard => 0.0,
+        }
+    }
+}
+
+// A server-controlled player. Bots have an ordinary player row, so they score, rank and
+// respawn like everyone else.
+#[table(name = bot, public)]
+#[derive(Debug, Clone)]
+pub struct Bot {
+    #[primary_key]
+    pub player_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub difficulty: BotDifficulty,
+    // The bot keeps its last decision until this time
+    pub next_think_at: Timestamp,
+}
+
+// Timers
+#[table(name = bot_timer, scheduled(update_bots))]
+pub struct BotTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// Reducers
+#[reducer]
+pub fn update_bots(ctx: &ReducerContext, _timer: BotTimer) -> Result<(), String> {
+    for mut bot in ctx.db.bot().iter() {
+        if ctx.timestamp < bot.next_think_at {
+            continue;
+        }
+        think(ctx, &bot);
+        bot.next_think_at = ctx.timestamp + Duration::from_millis(bot.difficulty.reaction_ms());
+        ctx.db.bot().player_id().update(bot);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn set_bots(ctx: &ReducerContext, arena_id: u32, fill: u32, difficulty: BotDifficulty) -> Result<(), String> {
+    require_admin(ctx)?;
+    let config = get_config(ctx, arena_id)?;
+    ctx.db.config().arena_id().update(Config {
+        bot_fill: fill,
+        bot_difficulty: difficulty,
+        ..config
+    });
+    for bot in ctx.db.bot().arena_id().filter(arena_id) {
+        ctx.db.bot().player_id().update(Bot { difficulty, ..bot });
+    }
+    balance_bots(ctx, arena_id)
+}
+
+pub fn is_bot(ctx: &ReducerContext, player_id: u32) -> bool {
+    ctx.db.bot().player_id().find(player_id).is_some()
+}
+
+// Adds or removes bots so the arena has its configured number of players. Arenas without any
+// humans in them don't get bots.
+pub fn balance_bots(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let Some(arena) = ctx.db.arena().arena_id().find(arena_id) else {
+        return Ok(());
+    };
+    let config = get_config(ctx, arena_id)?;
+    let bots: Vec<Bot> = ctx.db.bot().arena_id().filter(arena_id).collect();
+    let humans = arena.player_count.saturating_sub(bots.len() as u32);
+    // Always leave a slot free so a human can take a bot's place
+    let wanted = if humans == 0 {
+        0
+    } else {
+        config
+            .bot_fill
+            .saturating_sub(humans)
+            .min(arena.capacity.saturating_sub(humans + 1))
+    } as usize;
+
+    if bots.len() > wanted {
+        // The newest bots go first
+        let mut bots = bots;
+        bots.sort_by_key(|bot| bot.player_id);
+        for bot in bots.into_iter().skip(wanted) {
+            remove_bot(ctx, bot.player_id)?;
+        }
+    } else {
+        for _ in bots.len()..wanted {
+            add_bot(ctx, arena_id, config.bot_difficulty)?;
+        }
+    }
+    refresh_player_count(ctx, arena_id);
+    Ok(())
+}
+
+pub fn remove_bots(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    for bot in ctx.db.bot().arena_id().filter(arena_id) {
+        remove_bot(ctx, bot.player_id)?;
+    }
+    refresh_player_count(ctx, arena_id);
+    Ok(())
+}
+
+fn add_bot(ctx: &ReducerContext, arena_id: u32, difficulty: BotDifficulty) -> Result<(), String> {
+    // Bots never connect, so they get a made-up identity of their own
+    let subject = format!("{}-{}", arena_id, ctx.rng().gen::<u64>());
+    let player = ctx.db.player().try_insert(Player {
+        identity: Identity::from_claims("bot", &subject),
+        player_id: 0,
+        name: String::new(),
+        arena_id: Some(arena_id),
+        skill_rating: START_SKILL_RATING,
+        team_id: None,
+    })?;
+    let player_id = player.player_id;
+    log::info!("Adding bot {} to arena {}", player_id, arena_id);
+    ctx.db.player().identity().update(Player {
+        name: format!("Bot {}", player_id),
+        ..player
+    });
+    ctx.db.bot().try_insert(Bot {
+        player_id,
+        arena_id,
+        difficulty,
+        next_think_at: ctx.timestamp,
+    })?;
+    reset_score(ctx, player_id, Some(arena_id))?;
+    assign_team(ctx, player_id)?;
+    spawn_player(ctx, player_id, arena_id)
+}
+
+fn remove_bot(ctx: &ReducerContext, player_id: u32) -> Result<(), String> {
+    log::info!("Removing bot {}", player_id);
+    remove_player_ufos(ctx, player_id)?;
+    reset_score(ctx, player_id, None)?;
+    ctx.db.player().player_id().delete(player_id);
+    ctx.db.bot().player_id().delete(player_id);
+    Ok(())
+}
+
+// Picks a heading and beam state for each of the bot's UFOs. In order of priority, bots run from
+// bigger UFOs that could shove them around, take full holds to a drop-off zone and go after the nearest cow.
+fn think(ctx: &ReducerContext, bot: &Bot) {
+    let sight = bot.difficulty.sight_radius();
+    let mut rng = ctx.rng();
+    for mut ufo in ctx.db.ufo().player_id().filter(bot.player_id) {
+        let Some(ufo_entity) = ctx.db.entity().entity_id().find(ufo.entity_id) else {
+            continue;
+        };
+        let position = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+        let nearest = |points: &mut dyn Iterator<Item = DbVector2>| {
+            points
+                .map(|point| (point, (point - position).magnitude()))
+                .filter(|(_, distance)| *distance <= sight)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+        };
+
+        let threat = nearest(
+            &mut ctx
+                .db
+                .ufo()
+                .arena_id()
+                .filter(bot.arena_id)
+                .filter(|other| {
+                    other.player_id != bot.player_id && !are_teammates(ctx, other.player_id, bot.player_id)
+                })
+                .filter_map(|other| ctx.db.entity().entity_id().find(other.entity_id))
+                .filter(|other| other.mass > ufo_entity.mass)
+                .map(|other| DbVector2::new(other.position.x, other.position.z)),
+        );
+
+        let (heading, beam_on) = if let Some((threat_pos, _)) = threat {
+            (Some(position - threat_pos), false)
+        } else if ctx
+            .db
+            .cow()
+            .arena_id()
+            .filter(bot.arena_id)
+            .any(|cow| cow.abducted_by.as_ref().is_some_and(|by| by.entity_id == ufo.entity_id))
+        {
+            // Hold still until the cow is in the hold
+            (None, true)
+        } else if is_hold_full(ctx, ufo.entity_id, ufo_entity.mass) {
+            let zone = ctx
+                .db
+                .drop_off_zone()
+                .arena_id()
+                .filter(bot.arena_id)
+                .map(|zone| DbVector2::new(zone.position.x, zone.position.z))
+                .min_by(|a, b| (*a - position).magnitude().total_cmp(&(*b - position).magnitude()));
+            (zone.map(|zone| zone - position), false)
+        } else if let Some((cow_pos, distance)) = nearest(
+            &mut ctx
+                .db
+                .cow()
+                .arena_id()
+                .filter(bot.arena_id)
+                .filter(|cow| !cow.is_being_abducted)
+                .filter_map(|cow| ctx.db.entity().entity_id().find(cow.entity_id))
+                .map(|cow| DbVector2::new(cow.position.x, cow.position.z)),
+        ) {
+            let beam_radius = altitude_to_beam_radius(ufo_entity.position.y);
+            // Close enough to beam the cow up, so stop and do it
+            if distance <= beam_radius {
+                (None, true)
+            } else {
+                (Some(cow_pos - position), false)
+            }
+        } else if ufo.speed > 0.0 && !rng.gen_bool(BOT_WANDER_TURN_CHANCE) {
+            (Some(DbVector2::new(ufo.direction.x, ufo.direction.z)), false)
+        } else {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            (Some(DbVector2::new(angle.cos(), angle.sin())), false)
+        };
+
+        match heading.filter(|heading| heading.sqr_magnitude() > 0.0) {
+            Some(heading) => {
+                let error = bot.difficulty.aim_error();
+                let angle = heading.y.atan2(heading.x) + rng.gen_range(-error..=error);
+                ufo.direction = DbVector3::new(angle.cos(), ufo.direction.y, angle.sin());
+                ufo.speed = bot.difficulty.speed();
+            }
+            None => ufo.speed = 0.0,
+        }
+        set_beam(ctx, &mut ufo, beam_on);
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+}
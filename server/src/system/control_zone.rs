@@ -0,0 +1,141 @@
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, Timestamp};
+use std::collections::BTreeSet;
+use std::time::Duration;
+use crate::{
+    entity::entity::entity,
+    entity::ufo::ufo,
+    system::score::award_points,
+    system::system::get_config,
+    system::team::{add_team_points, team_of},
+    util::constants::{
+        CONTROL_ZONE_AWARD_MS, CONTROL_ZONE_POINTS, CONTROL_ZONE_RADIUS, CONTROL_ZONE_RELOCATE_MS,
+    },
+    util::math::DbVector2,
+};
+
+// The hill in king of the hill arenas. Whoever has it to themselves scores points every second.
+#[table(name = control_zone, public)]
+#[derive(Debug, Clone)]
+pub struct ControlZone {
+    #[primary_key]
+    pub arena_id: u32,
+    pub center: DbVector2,
+    pub radius: f32,
+    // Set when a single player, or a single team, is holding the zone
+    pub holder_player_id: Option<u32>,
+    pub holder_team_id: Option<u32>,
+    // More than one player or team is inside, so nobody scores
+    pub contested: bool,
+    pub next_award_at: Timestamp,
+}
+
+// Timers
+#[table(name = control_zone_timer, scheduled(relocate_control_zone))]
+pub struct ControlZoneTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
+}
+
+// Who a UFO in the zone is holding it for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Holder {
+    Player(u32),
+    Team(u32),
+}
+
+// Reducers
+#[reducer]
+pub fn relocate_control_zone(ctx: &ReducerContext, timer: ControlZoneTimer) -> Result<(), String> {
+    let Some(mut zone) = ctx.db.control_zone().arena_id().find(timer.arena_id) else {
+        return Ok(());
+    };
+    zone.center = random_zone_center(ctx, timer.arena_id)?;
+    ctx.db.control_zone().arena_id().update(zone);
+    Ok(())
+}
+
+pub fn start_control_zone(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    ctx.db.control_zone().try_insert(ControlZone {
+        arena_id,
+        center: random_zone_center(ctx, arena_id)?,
+        radius: CONTROL_ZONE_RADIUS,
+        holder_player_id: None,
+        holder_team_id: None,
+        contested: false,
+        next_award_at: ctx.timestamp,
+    })?;
+    ctx.db.control_zone_timer().try_insert(ControlZoneTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(CONTROL_ZONE_RELOCATE_MS).into()),
+        arena_id,
+    })?;
+    Ok(())
+}
+
+pub fn stop_control_zone(ctx: &ReducerContext, arena_id: u32) {
+    for timer in ctx.db.control_zone_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.control_zone_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    ctx.db.control_zone().arena_id().delete(arena_id);
+}
+
+// Works out who holds the zone and pays them out once a second
+pub fn score_control_zone(ctx: &ReducerContext, arena_id: u32) {
+    let Some(mut zone) = ctx.db.control_zone().arena_id().find(arena_id) else {
+        return;
+    };
+    let holders: BTreeSet<Holder> = ctx
+        .db
+        .ufo()
+        .arena_id()
+        .filter(arena_id)
+        .filter(|ufo| {
+            ctx.db.entity().entity_id().find(ufo.entity_id).is_some_and(|ufo_entity| {
+                let ufo_pos = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+                (ufo_pos - zone.center).magnitude() <= zone.radius
+            })
+        })
+        .map(|ufo| match team_of(ctx, ufo.player_id) {
+            Some(team_id) => Holder::Team(team_id),
+            None => Holder::Player(ufo.player_id),
+        })
+        .collect();
+
+    let holder = match holders.len() {
+        1 => holders.first().copied(),
+        _ => None,
+    };
+    let before = (zone.contested, zone.holder_player_id, zone.holder_team_id, zone.next_award_at);
+    zone.contested = holders.len() > 1;
+    zone.holder_player_id = match holder {
+        Some(Holder::Player(player_id)) => Some(player_id),
+        _ => None,
+    };
+    zone.holder_team_id = match holder {
+        Some(Holder::Team(team_id)) => Some(team_id),
+        _ => None,
+    };
+
+    if ctx.timestamp >= zone.next_award_at {
+        match holder {
+            Some(Holder::Player(player_id)) => award_points(ctx, player_id, CONTROL_ZONE_POINTS),
+            Some(Holder::Team(team_id)) => add_team_points(ctx, team_id, CONTROL_ZONE_POINTS),
+            None => {}
+        }
+        zone.next_award_at = ctx.timestamp + Duration::from_millis(CONTROL_ZONE_AWARD_MS);
+    }
+    // Only touch the row when something changed, so subscribers aren't sent an update every tick
+    if (zone.contested, zone.holder_player_id, zone.holder_team_id, zone.next_award_at) != before {
+        ctx.db.control_zone().arena_id().update(zone);
+    }
+}
+
+fn random_zone_center(ctx: &ReducerContext, arena_id: u32) -> Result<DbVector2, String> {
+    get_config(ctx, arena_id)?
+        .bounds
+        .random_point(&mut ctx.rng(), CONTROL_ZONE_RADIUS)
+        .ok_or("No room for the control zone".to_string())
+}
@@ -0,0 +1,249 @@
+use serde::Deserialize;
+use spacetimedb::{reducer, table, ReducerContext, Table};
+use crate::{
+    entity::obstacle::{obstacle, spawn_obstacle, CollisionShape, ObstacleKind},
+    system::system::{config, get_config, require_admin},
+    util::bounds::BoundsDefinition,
+    util::math::{DbVector2, DbVector3},
+};
+
+pub const DEFAULT_MAP: &str = "farm";
+
+// Maps that are compiled into the module, by name
+const EMBEDDED_MAPS: &[(&str, &str)] = &[("farm", include_str!("../../maps/farm.json"))];
+
+// Areas cows prefer to spawn in
+#[table(name = pasture, public)]
+pub struct Pasture {
+    #[primary_key]
+    #[auto_inc]
+    pub pasture_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub position: DbVector3,
+    pub radius: f32,
+}
+
+#[table(name = spawn_point, public)]
+pub struct SpawnPoint {
+    #[primary_key]
+    #[auto_inc]
+    pub spawn_point_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub position: DbVector3,
+}
+
+#[table(name = drop_off_zone, public)]
+pub struct DropOffZone {
+    #[primary_key]
+    #[auto_inc]
+    pub zone_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub position: DbVector3,
+    pub radius: f32,
+}
+
+// Map file format
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MapDefinition {
+    pub name: String,
+    // Set on maps that came out of the generator
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub bounds: BoundsDefinition,
+    #[serde(default)]
+    pub obstacles: Vec<ObstacleDefinition>,
+    #[serde(default)]
+    pub pastures: Vec<CircleDefinition>,
+    #[serde(default)]
+    pub spawn_points: Vec<[f32; 2]>,
+    #[serde(default)]
+    pub drop_off_zones: Vec<CircleDefinition>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ObstacleDefinition {
+    pub kind: ObstacleKind,
+    pub position: [f32; 2],
+    pub shape: ShapeDefinition,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShapeDefinition {
+    Circle { radius: f32 },
+    Rect { width: f32, depth: f32 },
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CircleDefinition {
+    pub position: [f32; 2],
+    pub radius: f32,
+}
+
+impl ShapeDefinition {
+    pub fn to_collision_shape(self) -> CollisionShape {
+        match self {
+            ShapeDefinition::Circle { radius } => CollisionShape::Circle(radius),
+            ShapeDefinition::Rect { width, depth } => CollisionShape::Rect(DbVector2::new(width / 2.0, depth / 2.0)),
+        }
+    }
+
+    // Radius of the smallest circle around the shape
+    pub fn bounding_radius(self) -> f32 {
+        match self {
+            ShapeDefinition::Circle { radius } => radius,
+            ShapeDefinition::Rect { width, depth } => DbVector2::new(width, depth).magnitude() / 2.0,
+        }
+    }
+}
+
+// Reducers
+#[reducer]
+pub fn upload_map(ctx: &ReducerContext, arena_id: u32, map_json: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let map = parse_map(&map_json)?;
+    load_map(ctx, arena_id, &map)
+}
+
+#[reducer]
+pub fn load_embedded_map(ctx: &ReducerContext, arena_id: u32, name: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let map = embedded_map(&name)?;
+    load_map(ctx, arena_id, &map)
+}
+
+pub fn embedded_map(name: &str) -> Result<MapDefinition, String> {
+    let (_, map_json) = EMBEDDED_MAPS
+        .iter()
+        .find(|(map_name, _)| *map_name == name)
+        .ok_or(format!("No embedded map named {}", name))?;
+    parse_map(map_json)
+}
+
+pub fn parse_map(map_json: &str) -> Result<MapDefinition, String> {
+    let map: MapDefinition = serde_json::from_str(map_json).map_err(|e| format!("Invalid map: {}", e))?;
+    validate_map(&map)?;
+    Ok(map)
+}
+
+pub fn validate_map(map: &MapDefinition) -> Result<(), String> {
+    if map.name.is_empty() {
+        return Err("Map name must not be empty".to_string());
+    }
+    let bounds = map.bounds.to_world_bounds();
+    bounds.validate()?;
+    let in_bounds = |position: [f32; 2]| {
+        position.iter().all(|value| value.is_finite()) && bounds.contains(to_ground(position))
+    };
+    let positive = |value: f32| value.is_finite() && value > 0.0;
+
+    for obstacle in &map.obstacles {
+        if !in_bounds(obstacle.position) {
+            return Err(format!("{:?} at {:?} is outside the map", obstacle.kind, obstacle.position));
+        }
+        let valid_shape = match obstacle.shape {
+            ShapeDefinition::Circle { radius } => positive(radius),
+            ShapeDefinition::Rect { width, depth } => positive(width) && positive(depth),
+        };
+        if !valid_shape {
+            return Err(format!("{:?} at {:?} has an empty shape", obstacle.kind, obstacle.position));
+        }
+    }
+    for zone in map.pastures.iter().chain(map.drop_off_zones.iter()) {
+        if !in_bounds(zone.position) {
+            return Err(format!("Zone at {:?} is outside the map", zone.position));
+        }
+        if !positive(zone.radius) {
+            return Err(format!("Zone at {:?} must have a positive radius", zone.position));
+        }
+    }
+    for spawn_point in &map.spawn_points {
+        if !in_bounds(*spawn_point) {
+            return Err(format!("Spawn point {:?} is outside the map", spawn_point));
+        }
+    }
+    Ok(())
+}
+
+// Replaces an arena's world layout with the given map
+pub fn load_map(ctx: &ReducerContext, arena_id: u32, map: &MapDefinition) -> Result<(), String> {
+    log::info!("Loading map {} into arena {}", map.name, arena_id);
+    let mut config = get_config(ctx, arena_id)?;
+    config.bounds = map.bounds.to_world_bounds();
+    config.map_name = map.name.clone();
+    config.map_seed = map.seed;
+    ctx.db.config().arena_id().update(config);
+    clear_map(ctx, arena_id);
+
+    for obstacle in &map.obstacles {
+        spawn_obstacle(ctx, arena_id, obstacle.kind, to_ground(obstacle.position), obstacle.shape.to_collision_shape())?;
+    }
+    for pasture in &map.pastures {
+        ctx.db.pasture().try_insert(Pasture {
+            pasture_id: 0,
+            arena_id,
+            position: to_world(pasture.position),
+            radius: pasture.radius,
+        })?;
+    }
+    for spawn_point in &map.spawn_points {
+        ctx.db.spawn_point().try_insert(SpawnPoint {
+            spawn_point_id: 0,
+            arena_id,
+            position: to_world(*spawn_point),
+        })?;
+    }
+    for zone in &map.drop_off_zones {
+        ctx.db.drop_off_zone().try_insert(DropOffZone {
+            zone_id: 0,
+            arena_id,
+            position: to_world(zone.position),
+            radius: zone.radius,
+        })?;
+    }
+    Ok(())
+}
+
+pub fn clear_map(ctx: &ReducerContext, arena_id: u32) {
+    ctx.db.obstacle().arena_id().delete(arena_id);
+    ctx.db.pasture().arena_id().delete(arena_id);
+    ctx.db.spawn_point().arena_id().delete(arena_id);
+    ctx.db.drop_off_zone().arena_id().delete(arena_id);
+}
+
+fn to_ground([x, z]: [f32; 2]) -> DbVector2 {
+    DbVector2::new(x, z)
+}
+
+fn to_world([x, z]: [f32; 2]) -> DbVector3 {
+    DbVector3::new(x, 0.0, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_embedded_maps() {
+        for (name, _) in EMBEDDED_MAPS {
+            assert!(embedded_map(name).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn rejects_non_finite_numbers() {
+        // serde_json reads numbers too big for an f32 as infinity
+        let maps = [
+            r#"{"name": "m", "bounds": {"rect": {"width": 1e39, "depth": 10}}}"#,
+            r#"{"name": "m", "bounds": {"polygon": [[0, 0], [1e39, 0], [0, 10]]}}"#,
+            r#"{"name": "m", "bounds": {"rect": {"width": 10, "depth": 10}}, "pastures": [{"position": [5, 5], "radius": 1e39}]}"#,
+            r#"{"name": "m", "bounds": {"rect": {"width": 10, "depth": 10}}, "obstacles": [{"kind": "tree", "position": [5, 5], "shape": {"rect": {"width": 1e39, "depth": 1}}}]}"#,
+        ];
+        for map in maps {
+            assert!(parse_map(map).is_err(), "{}", map);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use spacetimedb::{reducer, ReducerContext};
+use std::collections::VecDeque;
+use crate::{
+    entity::obstacle::{CollisionShape, ObstacleKind},
+    system::map::{
+        load_map, validate_map, CircleDefinition, MapDefinition, ObstacleDefinition,
+        ShapeDefinition,
+    },
+    system::system::require_admin,
+    util::bounds::BoundsDefinition,
+    util::constants::{
+        GENERATED_DROP_OFF_ZONES, GENERATED_FEATURE_SPACING, GENERATED_GRID_CELL_SIZE,
+        GENERATED_MAX_SIZE, GENERATED_PATH_CLEARANCE, GENERATED_PLACEMENT_ATTEMPTS, GENERATED_SPAWN_POINTS,
+    },
+    util::math::DbVector2,
+};
+
+// SplitMix64. The generator uses its own RNG rather than ctx.rng() so that a seed always
+// produces the same map, whatever version of the rand crate the module is built against.
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    // Uniform in [min, max]
+    pub fn range_usize(&mut self, min: usize, max: usize) -> usize {
+        min + (self.next_u64() % (max - min + 1) as u64) as usize
+    }
+}
+
+// Reducers
+#[reducer]
+pub fn generate_map(ctx: &ReducerContext, arena_id: u32, seed: u64, width: f32, depth: f32) -> Result<(), String> {
+    require_admin(ctx)?;
+    let map = generate(seed, width, depth)?;
+    load_map(ctx, arena_id, &map)
+}
+
+// Builds a map from a seed. The same seed and size always give the same map.
+pub fn generate(seed: u64, width: f32, depth: f32) -> Result<MapDefinition, String> {
+    let valid_size = |length: f32| length.is_finite() && length > 0.0 && length <= GENERATED_MAX_SIZE;
+    if !valid_size(width) || !valid_size(depth) {
+        return Err(format!("Generated maps must be between 0 and {} across", GENERATED_MAX_SIZE));
+    }
+    let mut rng = SeededRng::new(seed);
+    let size = DbVector2::new(width, depth);
+    // Footprints of everything placed so far, used to keep features apart
+    let mut placed: Vec<(DbVector2, f32)> = Vec::new();
+    let mut map = MapDefinition {
+        name: format!("generated-{}-{}x{}", seed, width, depth),
+        seed: Some(seed),
+        bounds: BoundsDefinition::Rect { width, depth },
+        obstacles: Vec::new(),
+        pastures: Vec::new(),
+        spawn_points: Vec::new(),
+        drop_off_zones: Vec::new(),
+    };
+
+    for _ in 0..GENERATED_SPAWN_POINTS {
+        if let Some(position) = place(&mut rng, size, &mut placed, GENERATED_PATH_CLEARANCE) {
+            map.spawn_points.push([position.x, position.y]);
+        }
+    }
+    for _ in 0..GENERATED_DROP_OFF_ZONES {
+        if let Some(position) = place(&mut rng, size, &mut placed, 0.5) {
+            map.drop_off_zones.push(CircleDefinition { position: [position.x, position.y], radius: 0.5 });
+        }
+    }
+    for _ in 0..rng.range_usize(2, 3) {
+        let radius = rng.range_f32(1.0, 1.5);
+        if let Some(position) = place(&mut rng, size, &mut placed, radius) {
+            map.pastures.push(CircleDefinition { position: [position.x, position.y], radius });
+        }
+    }
+    for _ in 0..rng.range_usize(1, 2) {
+        let shape = ShapeDefinition::Circle { radius: rng.range_f32(0.5, 1.0) };
+        place_obstacle(&mut rng, size, &mut placed, &mut map, ObstacleKind::Pond, shape);
+    }
+    for _ in 0..rng.range_usize(4, 8) {
+        let (kind, shape) = random_obstacle(&mut rng);
+        place_obstacle(&mut rng, size, &mut placed, &mut map, kind, shape);
+    }
+
+    // Obstacles are only ever removed here, so this always terminates with a connected map
+    while let Some(blocker) = find_blocker(&map, size) {
+        map.obstacles.remove(blocker);
+    }
+
+    validate_map(&map)?;
+    Ok(map)
+}
+
+fn random_obstacle(rng: &mut SeededRng) -> (ObstacleKind, ShapeDefinition) {
+    match rng.range_usize(0, 3) {
+        0 => (ObstacleKind::Barn, ShapeDefinition::Rect { width: rng.range_f32(1.0, 1.6), depth: rng.range_f32(0.8, 1.2) }),
+        1 => (ObstacleKind::Silo, ShapeDefinition::Circle { radius: rng.range_f32(0.3, 0.5) }),
+        2 => {
+            let length = rng.range_f32(1.5, 3.0);
+            // Fences run either east-west or north-south
+            if rng.next_f32() < 0.5 {
+                (ObstacleKind::Fence, ShapeDefinition::Rect { width: length, depth: 0.1 })
+            } else {
+                (ObstacleKind::Fence, ShapeDefinition::Rect { width: 0.1, depth: length })
+            }
+        }
+        _ => (ObstacleKind::Tree, ShapeDefinition::Circle { radius: rng.range_f32(0.15, 0.25) }),
+    }
+}
+
+fn place_obstacle(
+    rng: &mut SeededRng,
+    size: DbVector2,
+    placed: &mut Vec<(DbVector2, f32)>,
+    map: &mut MapDefinition,
+    kind: ObstacleKind,
+    shape: ShapeDefinition,
+) {
+    if let Some(position) = place(rng, size, placed, shape.bounding_radius()) {
+        map.obstacles.push(ObstacleDefinition { kind, position: [position.x, position.y], shape });
+    }
+}
+
+// Finds a spot for a feature of the given radius that keeps the minimum spacing to everything
+// already placed. Gives up after a fixed number of attempts so a crowded map stays deterministic.
+fn place(rng: &mut SeededRng, size: DbVector2, placed: &mut Vec<(DbVector2, f32)>, radius: f32) -> Option<DbVector2> {
+    if radius * 2.0 >= size.x || radius * 2.0 >= size.y {
+        return None;
+    }
+    for _ in 0..GENERATED_PLACEMENT_ATTEMPTS {
+        let candidate = DbVector2::new(rng.range_f32(radius, size.x - radius), rng.range_f32(radius, size.y - radius));
+        let clear = placed.iter().all(|(position, other_radius)| {
+            (candidate - *position).magnitude() >= radius + other_radius + GENERATED_FEATURE_SPACING
+        });
+        if clear {
+            placed.push((candidate, radius));
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+// Flood fills a grid over the walkable ground and checks that every spawn point, pasture and
+// drop-off zone can be reached from every other one. If they can't, returns the index of the
+// obstacle most in the way: one covering a feature, or else the one walling in the most of the
+// ground that can be reached.
+fn find_blocker(map: &MapDefinition, size: DbVector2) -> Option<usize> {
+    let columns = ((size.x / GENERATED_GRID_CELL_SIZE).ceil() as usize).max(1);
+    let rows = ((size.y / GENERATED_GRID_CELL_SIZE).ceil() as usize).max(1);
+    let solid: Vec<(usize, DbVector2, CollisionShape)> = map
+        .obstacles
+        .iter()
+        .enumerate()
+        .filter(|(_, obstacle)| obstacle.kind.is_solid())
+        .map(|(index, obstacle)| {
+            (index, DbVector2::new(obstacle.position[0], obstacle.position[1]), obstacle.shape.to_collision_shape())
+        })
+        .collect();
+    let cell_center = |x: usize, z: usize| {
+        DbVector2::new((x as f32 + 0.5) * GENERATED_GRID_CELL_SIZE, (z as f32 + 0.5) * GENERATED_GRID_CELL_SIZE)
+    };
+    // Every obstacle covering each cell
+    let blockers: Vec<Vec<usize>> = (0..columns * rows)
+        .map(|i| {
+            let center = cell_center(i % columns, i / columns);
+            solid
+                .iter()
+                .filter(|(_, position, shape)| shape.overlaps(*position, center, GENERATED_PATH_CLEARANCE))
+                .map(|(index, _, _)| *index)
+                .collect()
+        })
+        .collect();
+    let to_cell = |[x, z]: [f32; 2]| {
+        let column = ((x / GENERATED_GRID_CELL_SIZE) as usize).min(columns - 1);
+        let row = ((z / GENERATED_GRID_CELL_SIZE) as usize).min(rows - 1);
+        row * columns + column
+    };
+
+    let targets: Vec<usize> = map
+        .spawn_points
+        .iter()
+        .copied()
+        .chain(map.pastures.iter().map(|pasture| pasture.position))
+        .chain(map.drop_off_zones.iter().map(|zone| zone.position))
+        .map(to_cell)
+        .collect();
+    let &start = targets.first()?;
+    if let Some(target) = targets.iter().find(|target| !blockers[**target].is_empty()) {
+        return blockers[*target].first().copied();
+    }
+
+    let neighbours = |cell: usize| {
+        let (column, row) = (cell % columns, cell / columns);
+        [
+            (column > 0).then(|| cell - 1),
+            (column + 1 < columns).then(|| cell + 1),
+            (row > 0).then(|| cell - columns),
+            (row + 1 < rows).then(|| cell + columns),
+        ]
+        .into_iter()
+        .flatten()
+    };
+    let mut visited = vec![false; columns * rows];
+    let mut queue = VecDeque::from([start]);
+    visited[start] = true;
+    // How many blocked cells on the edge of the reachable ground each obstacle covers
+    let mut walls = vec![0usize; map.obstacles.len()];
+    let mut counted = vec![false; columns * rows];
+    while let Some(cell) = queue.pop_front() {
+        for next in neighbours(cell) {
+            if visited[next] {
+                continue;
+            }
+            if blockers[next].is_empty() {
+                visited[next] = true;
+                queue.push_back(next);
+            } else if !counted[next] {
+                counted[next] = true;
+                for index in &blockers[next] {
+                    walls[*index] += 1;
+                }
+            }
+        }
+    }
+    if targets.iter().all(|target| visited[*target]) {
+        return None;
+    }
+    // Ties go to the obstacle placed first, so the result only depends on the map
+    walls
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .max_by(|(index_a, count_a), (index_b, count_b)| count_a.cmp(count_b).then(index_b.cmp(index_a)))
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_and_size_give_the_same_map() {
+        for seed in [0, 1, 42, u64::MAX] {
+            assert_eq!(generate(seed, 10.0, 10.0), generate(seed, 10.0, 10.0));
+        }
+    }
+
+    #[test]
+    fn seed_and_size_change_the_map() {
+        let map = generate(7, 10.0, 10.0).unwrap();
+        assert_ne!(Ok(map.clone()), generate(8, 10.0, 10.0));
+        assert_ne!(Ok(map), generate(7, 12.0, 8.0));
+    }
+
+    #[test]
+    fn generated_maps_are_connected() {
+        for seed in 0..50 {
+            let map = generate(seed, 10.0, 10.0).unwrap();
+            assert_eq!(find_blocker(&map, DbVector2::new(10.0, 10.0)), None, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_sizes() {
+        assert!(generate(1, f32::NAN, 10.0).is_err());
+        assert!(generate(1, 10.0, 0.0).is_err());
+        assert!(generate(1, GENERATED_MAX_SIZE * 2.0, 10.0).is_err());
+    }
+}
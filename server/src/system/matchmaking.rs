@@ -0,0 +1,192 @@
+use spacetimedb::{reducer, table, Identity, ReducerContext, ScheduleAt, Table, Timestamp};
+use std::collections::BTreeMap;
+use crate::{
+    system::arena::{
+        arena, create_arena_with_map, move_player_to_arena, reap_empty_arenas, Arena, DEFAULT_ARENA_CAPACITY,
+    },
+    system::bot::is_bot,
+    system::map::{embedded_map, DEFAULT_MAP},
+    system::player::{player, Player},
+    system::system::spawn_player,
+    util::constants::{
+        MATCHMAKING_MAX_WAIT_MS, MATCHMAKING_MIN_GROUP_SIZE, MATCHMAKING_SKILL_WINDOW,
+        MATCHMAKING_SKILL_WINDOW_GROWTH_PER_SEC,
+    },
+};
+
+// Players waiting to be placed into an arena
+#[table(name = matchmaking_queue, public)]
+#[derive(Debug, Clone)]
+pub struct MatchmakingQueue {
+    #[primary_key]
+    pub identity: Identity,
+    pub player_id: u32,
+    pub region: String,
+    pub skill_rating: u32,
+    pub enqueued_at: Timestamp,
+}
+
+// Timers
+#[table(name = matchmaking_timer, scheduled(run_matchmaker))]
+pub struct MatchmakingTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// Reducers
+#[reducer]
+pub fn join_queue(ctx: &ReducerContext, region: String) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if player.arena_id.is_some() {
+        return Err("Leave your arena before joining the queue".to_string());
+    }
+    enqueue_player(ctx, &player, region)
+}
+
+#[reducer]
+pub fn leave_queue(ctx: &ReducerContext) -> Result<(), String> {
+    if !ctx.db.matchmaking_queue().identity().delete(ctx.sender) {
+        return Err("Not in the queue".to_string());
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn run_matchmaker(ctx: &ReducerContext, _timer: MatchmakingTimer) -> Result<(), String> {
+    reap_empty_arenas(ctx)?;
+    let mut by_region: BTreeMap<String, Vec<MatchmakingQueue>> = BTreeMap::new();
+    for entry in ctx.db.matchmaking_queue().iter() {
+        by_region.entry(entry.region.clone()).or_default().push(entry);
+    }
+
+    for (region, mut waiting) in by_region {
+        // First come, first served
+        waiting.sort_by_key(|entry| entry.enqueued_at);
+        let mut unplaced = Vec::new();
+        for entry in waiting {
+            match find_arena_for(ctx, &entry) {
+                Some(arena) => place_player(ctx, entry, arena.arena_id)?,
+                None => unplaced.push(entry),
+            }
+        }
+
+        // A new arena is only opened for a group of players close enough in skill, for a player
+        // who has waited too long for one to form, or when the region has no arenas at all
+        while !unplaced.is_empty() {
+            let anchor = unplaced[0].clone();
+            let window = skill_window(ctx, &anchor);
+            let (group, rest): (Vec<MatchmakingQueue>, Vec<MatchmakingQueue>) = unplaced
+                .into_iter()
+                .partition(|entry| entry.skill_rating.abs_diff(anchor.skill_rating) as f32 <= window);
+            unplaced = rest;
+            let waited_too_long = waited_ms(ctx, &anchor) >= MATCHMAKING_MAX_WAIT_MS;
+            let region_empty = ctx.db.arena().iter().all(|arena| arena.region != region);
+            if group.len() < MATCHMAKING_MIN_GROUP_SIZE && !waited_too_long && !region_empty {
+                continue;
+            }
+            let name = format!("{} #{}", region, ctx.db.arena().count() + 1);
+            let arena =
+                create_arena_with_map(ctx, name, region.clone(), DEFAULT_ARENA_CAPACITY, &embedded_map(DEFAULT_MAP)?)?;
+            // Anyone who doesn't fit waits for the next run, when the arena will have room or not
+            for entry in group.into_iter().take(arena.capacity as usize) {
+                place_player(ctx, entry, arena.arena_id)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn enqueue_player(ctx: &ReducerContext, player: &Player, region: String) -> Result<(), String> {
+    if region.is_empty() {
+        return Err("Region must not be empty".to_string());
+    }
+    log::info!("Queueing player {} in region {}", player.player_id, region);
+    let entry = MatchmakingQueue {
+        identity: player.identity,
+        player_id: player.player_id,
+        region,
+        skill_rating: player.skill_rating,
+        enqueued_at: ctx.timestamp,
+    };
+    // Re-queueing just updates the region, the player keeps their place in line
+    match ctx.db.matchmaking_queue().identity().find(player.identity) {
+        Some(existing) => {
+            ctx.db.matchmaking_queue().identity().update(MatchmakingQueue {
+                enqueued_at: existing.enqueued_at,
+                ..entry
+            });
+        }
+        None => {
+            ctx.db.matchmaking_queue().try_insert(entry)?;
+        }
+    }
+    Ok(())
+}
+
+// The arena in the player's region with room whose players are closest to them in skill. The
+// accepted skill gap widens the longer they've been waiting.
+fn find_arena_for(ctx: &ReducerContext, entry: &MatchmakingQueue) -> Option<Arena> {
+    let window = skill_window(ctx, entry);
+
+    ctx.db
+        .arena()
+        .iter()
+        .filter(|arena| arena.region == entry.region && arena.player_count < arena.capacity)
+        .filter_map(|arena| {
+            // Empty arenas take anyone
+            let skill_gap = average_skill(ctx, arena.arena_id)
+                .map_or(0.0, |skill| (skill - entry.skill_rating as f32).abs());
+            (skill_gap <= window).then_some((skill_gap, arena))
+        })
+        // Prefer the closest match, then the fullest arena so matches fill up
+        .min_by(|(gap_a, arena_a), (gap_b, arena_b)| {
+            gap_a
+                .total_cmp(gap_b)
+                .then(arena_b.player_count.cmp(&arena_a.player_count))
+        })
+        .map(|(_, arena)| arena)
+}
+
+fn waited_ms(ctx: &ReducerContext, entry: &MatchmakingQueue) -> u64 {
+    ctx.timestamp
+        .duration_since(entry.enqueued_at)
+        .map_or(0, |waited| waited.as_millis() as u64)
+}
+
+// The biggest skill gap the player accepts, which widens the longer they've been waiting
+fn skill_window(ctx: &ReducerContext, entry: &MatchmakingQueue) -> f32 {
+    MATCHMAKING_SKILL_WINDOW + waited_ms(ctx, entry) as f32 / 1000.0 * MATCHMAKING_SKILL_WINDOW_GROWTH_PER_SEC
+}
+
+fn average_skill(ctx: &ReducerContext, arena_id: u32) -> Option<f32> {
+    let skills: Vec<u32> = ctx
+        .db
+        .player()
+        .iter()
+        // Bots all sit at the starting rating, so they'd drag every arena towards it
+        .filter(|player| player.arena_id == Some(arena_id) && !is_bot(ctx, player.player_id))
+        .map(|player| player.skill_rating)
+        .collect();
+    if skills.is_empty() {
+        return None;
+    }
+    Some(skills.iter().sum::<u32>() as f32 / skills.len() as f32)
+}
+
+fn place_player(ctx: &ReducerContext, entry: MatchmakingQueue, arena_id: u32) -> Result<(), String> {
+    ctx.db.matchmaking_queue().identity().delete(entry.identity);
+    let Some(player) = ctx.db.player().identity().find(entry.identity) else {
+        return Ok(());
+    };
+    log::info!("Matched player {} into arena {}", player.player_id, arena_id);
+    let player_id = player.player_id;
+    move_player_to_arena(ctx, player, Some(arena_id))?;
+    spawn_player(ctx, player_id, arena_id)
+}
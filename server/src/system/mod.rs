@@ -1,2 +1,15 @@
+#[allow(clippy::module_inception)]
 pub mod system;
-pub mod player;
\ No newline at end of file
+pub mod player;
+pub mod map;
+pub mod map_generator;
+pub mod arena;
+pub mod matchmaking;
+pub mod round;
+pub mod score;
+pub mod stats;
+pub mod achievement;
+pub mod team;
+pub mod safe_zone;
+pub mod control_zone;
+pub mod bot;
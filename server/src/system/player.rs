@@ -1,7 +1,14 @@
 use spacetimedb::{reducer, table, Identity, ReducerContext};
+use std::time::Duration;
 use crate::{
-    entity::ufo::ufo,
-    util::math::{DbVector2, DbVector3}
+    entity::entity::entity,
+    entity::ufo::{ufo, Ufo},
+    system::system::get_config,
+    util::constants::{
+        BOOST_COOLDOWN_MS, BOOST_ENERGY_COST, BOOST_SPEED_MULTIPLIER, MIN_BEAM_ENERGY_TO_START,
+    },
+    util::math::{DbVector2, DbVector3},
+    util::util::mass_to_max_ufo_velocity,
 };
 use crate::entity::cow::cow;
 
@@ -14,6 +21,12 @@ pub struct Player {
     #[auto_inc]
     pub player_id: u32,
     pub name: String,
+    // The arena the player is playing in, if they've joined one
+    pub arena_id: Option<u32>,
+    // Used to match players of a similar level
+    pub skill_rating: u32,
+    // Only set in arenas playing with teams
+    pub team_id: Option<u32>,
 }
 
 // Reducers
@@ -23,9 +36,9 @@ pub fn update_player_input(ctx: &ReducerContext, direction: DbVector2) -> Result
         .db
         .player()
         .identity()
-        .find(&ctx.sender)
+        .find(ctx.sender)
         .ok_or("Player not found")?;
-    for mut ufo in ctx.db.ufo().player_id().filter(&player.player_id) {
+    for mut ufo in ctx.db.ufo().player_id().filter(player.player_id) {
         let norm = direction.normalized();
         ufo.direction = DbVector3 {
             x: norm.x,
@@ -38,26 +51,84 @@ pub fn update_player_input(ctx: &ReducerContext, direction: DbVector2) -> Result
     Ok(())
 }
 
+#[reducer]
+pub fn update_player_altitude(ctx: &ReducerContext, altitude: f32) -> Result<(), String> {
+    if !altitude.is_finite() {
+        return Err("Altitude must be a number".to_string());
+    }
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    let arena_id = player.arena_id.ok_or("Not in an arena")?;
+    let config = get_config(ctx, arena_id)?;
+    for mut ufo in ctx.db.ufo().player_id().filter(player.player_id) {
+        ufo.target_altitude = altitude.clamp(config.min_altitude, config.max_altitude);
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+    Ok(())
+}
+
 #[reducer]
 pub fn update_player_beam(ctx: &ReducerContext, beam_on: bool) -> Result<(), String> {
     let player = ctx
         .db
         .player()
         .identity()
-        .find(&ctx.sender)
+        .find(ctx.sender)
         .ok_or("Player not found")?;
-    for mut ufo in ctx.db.ufo().player_id().filter(&player.player_id) {
-        ufo.beam_on = beam_on;
-        if !ufo.beam_on {
-            if let Some(entity) = ufo.abducted_entity {
-                if let Some(mut cow) = ctx.db.cow().entity_id().find(entity.entity_id) {
-                    cow.is_being_abducted = false;
-                    cow.abducted_by = None;
-                    ctx.db.cow().entity_id().update(cow);
-                }
-            }
-            ufo.abducted_entity = None;
+    for mut ufo in ctx.db.ufo().player_id().filter(player.player_id) {
+        set_beam(ctx, &mut ufo, beam_on);
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+    Ok(())
+}
+
+// A short burst of speed in the direction the UFO is heading, paid for with beam energy
+#[reducer]
+pub fn boost(ctx: &ReducerContext) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    for mut ufo in ctx.db.ufo().player_id().filter(player.player_id) {
+        let holding_cow = ctx
+            .db
+            .cow()
+            .arena_id()
+            .filter(ufo.arena_id)
+            .any(|cow| cow.abducted_by.as_ref().is_some_and(|by| by.entity_id == ufo.entity_id));
+        if ufo.abducting || holding_cow {
+            return Err("Can't boost while abducting".to_string());
+        }
+        if ctx.timestamp < ufo.last_boost_time + Duration::from_millis(BOOST_COOLDOWN_MS) {
+            return Err("Boost is on cooldown".to_string());
+        }
+        if ufo.beam_energy < BOOST_ENERGY_COST {
+            return Err("Not enough beam energy to boost".to_string());
         }
+        let heading = if ufo.velocity.sqr_magnitude() > 0.0 {
+            ufo.velocity.normalized()
+        } else if ufo.speed > 0.0 {
+            ufo.direction
+        } else {
+            return Err("Can't boost while standing still".to_string());
+        };
+        let mass = ctx
+            .db
+            .entity()
+            .entity_id()
+            .find(ufo.entity_id)
+            .ok_or("UFO entity not found")?
+            .mass;
+
+        ufo.velocity = heading * mass_to_max_ufo_velocity(mass) * BOOST_SPEED_MULTIPLIER;
+        ufo.beam_energy -= BOOST_ENERGY_COST;
+        ufo.last_boost_time = ctx.timestamp;
         ctx.db.ufo().entity_id().update(ufo);
     }
     Ok(())
@@ -68,9 +139,9 @@ pub fn update_player_abducting(ctx: &ReducerContext, abducting: bool) -> Result<
         .db
         .player()
         .identity()
-        .find(&ctx.sender)
+        .find(ctx.sender)
         .ok_or("Player not found")?;
-    for mut ufo in ctx.db.ufo().player_id().filter(&player.player_id) {
+    for mut ufo in ctx.db.ufo().player_id().filter(player.player_id) {
         ufo.abducting = abducting;
         ctx.db.ufo().entity_id().update(ufo);
     }
@@ -102,4 +173,20 @@ pub fn validate_message(text: String) -> Result<String, String> {
     } else {
         Ok(text)
     }
-}
\ No newline at end of file
+}
+
+// Turns the UFO's beam on or off, letting go of whatever it was lifting. The caller saves the UFO.
+pub fn set_beam(ctx: &ReducerContext, ufo: &mut Ufo, beam_on: bool) {
+    // A drained beam has to recharge a little before it can be turned back on
+    ufo.beam_on = beam_on && (ufo.beam_on || ufo.beam_energy >= MIN_BEAM_ENERGY_TO_START);
+    if !ufo.beam_on {
+        if let Some(entity) = ufo.abducted_entity.take() {
+            if let Some(mut cow) = ctx.db.cow().entity_id().find(entity.entity_id) {
+                cow.is_being_abducted = false;
+                cow.abducted_by = None;
+                cow.contested_by.clear();
+                ctx.db.cow().entity_id().update(cow);
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp};
+use std::time::Duration;
+use crate::{
+    entity::cow::{cow, cow_respawn},
+    entity::entity::entity,
+    entity::ufo::ufo,
+    system::achievement::{emit_event, GameEvent},
+    system::bot::is_bot,
+    system::player::{player, Player},
+    system::safe_zone::{reset_safe_zone, safe_zone},
+    system::score::{reset_arena_scores, score},
+    system::system::{remove_player_ufos, spawn_player},
+    util::constants::{
+        MIN_PLAYERS_TO_START_ROUND, ROUND_COUNTDOWN_MS, ROUND_DURATION_MS, ROUND_RESULTS_MS,
+        ROUND_TIMER_INTERVAL_MS, SKILL_RATING_PER_RANK,
+    },
+};
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum RoundPhase {
+    // Waiting for enough players
+    Lobby,
+    Countdown,
+    Playing,
+    // Showing who won before the world resets
+    Results,
+}
+
+// The match currently running in each arena
+#[table(name = round, public)]
+#[derive(Debug, Clone)]
+pub struct Round {
+    #[primary_key]
+    pub arena_id: u32,
+    pub round_number: u32,
+    pub phase: RoundPhase,
+    pub phase_started_at: Timestamp,
+    pub phase_ends_at: Option<Timestamp>,
+}
+
+#[table(name = round_result, public)]
+pub struct RoundResult {
+    #[primary_key]
+    #[auto_inc]
+    pub result_id: u64,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub round_number: u32,
+    pub player_id: u32,
+    // 1 is the winner
+    pub rank: u32,
+    pub score: u32,
+    pub mass: u32,
+    pub recorded_at: Timestamp,
+}
+
+// Timers
+#[table(name = round_timer, scheduled(advance_round))]
+pub struct RoundTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
+}
+
+impl Round {
+    fn enter_phase(&mut self, phase: RoundPhase, now: Timestamp, duration_ms: Option<u64>) {
+        log::info!("Arena {} round {}: {:?} -> {:?}", self.arena_id, self.round_number, self.phase, phase);
+        self.phase = phase;
+        self.phase_started_at = now;
+        self.phase_ends_at = duration_ms.map(|ms| now + Duration::from_millis(ms));
+    }
+}
+
+// Reducers
+#[reducer]
+pub fn advance_round(ctx: &ReducerContext, timer: RoundTimer) -> Result<(), String> {
+    let arena_id = timer.arena_id;
+    let mut round = ctx.db.round().arena_id().find(arena_id).ok_or("Round not found")?;
+    let player_count = arena_players(ctx, arena_id).len();
+    let phase_over = round.phase_ends_at.is_some_and(|ends_at| ctx.timestamp >= ends_at);
+
+    match round.phase {
+        RoundPhase::Lobby => {
+            if player_count >= MIN_PLAYERS_TO_START_ROUND {
+                round.enter_phase(RoundPhase::Countdown, ctx.timestamp, Some(ROUND_COUNTDOWN_MS));
+            }
+        }
+        RoundPhase::Countdown => {
+            if player_count < MIN_PLAYERS_TO_START_ROUND {
+                round.enter_phase(RoundPhase::Lobby, ctx.timestamp, None);
+            } else if phase_over {
+                // Everyone starts the round on equal terms
+                reset_world(ctx, arena_id)?;
+                round.enter_phase(RoundPhase::Playing, ctx.timestamp, Some(ROUND_DURATION_MS));
+            }
+        }
+        RoundPhase::Playing => {
+            if player_count == 0 {
+                reset_world(ctx, arena_id)?;
+                round.enter_phase(RoundPhase::Lobby, ctx.timestamp, None);
+            } else if phase_over {
+                record_results(ctx, &round)?;
+                round.enter_phase(RoundPhase::Results, ctx.timestamp, Some(ROUND_RESULTS_MS));
+            }
+        }
+        RoundPhase::Results => {
+            if phase_over {
+                reset_world(ctx, arena_id)?;
+                round.round_number += 1;
+                round.enter_phase(RoundPhase::Lobby, ctx.timestamp, None);
+            }
+        }
+    }
+
+    ctx.db.round().arena_id().update(round);
+    Ok(())
+}
+
+pub fn start_rounds(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    ctx.db.round().try_insert(Round {
+        arena_id,
+        round_number: 1,
+        phase: RoundPhase::Lobby,
+        phase_started_at: ctx.timestamp,
+        phase_ends_at: None,
+    })?;
+    ctx.db.round_timer().try_insert(RoundTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(ROUND_TIMER_INTERVAL_MS).into()),
+        arena_id,
+    })?;
+    Ok(())
+}
+
+pub fn stop_rounds(ctx: &ReducerContext, arena_id: u32) {
+    for timer in ctx.db.round_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.round_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    ctx.db.round().arena_id().delete(arena_id);
+    ctx.db.round_result().arena_id().delete(arena_id);
+}
+
+pub fn is_round_in_play(ctx: &ReducerContext, arena_id: u32) -> bool {
+    ctx.db
+        .round()
+        .arena_id()
+        .find(arena_id)
+        .is_some_and(|round| round.phase == RoundPhase::Playing)
+}
+
+fn arena_players(ctx: &ReducerContext, arena_id: u32) -> Vec<Player> {
+    ctx.db
+        .player()
+        .iter()
+        .filter(|player| player.arena_id == Some(arena_id))
+        .collect()
+}
+
+// Ranks everyone in the arena by score, then total UFO mass, and nudges their skill rating
+fn record_results(ctx: &ReducerContext, round: &Round) -> Result<(), String> {
+    let mut standings: Vec<(Player, u32, u32)> = arena_players(ctx, round.arena_id)
+        .into_iter()
+        .map(|player| {
+            let score = ctx.db.score().player_id().find(player.player_id).map_or(0, |score| score.score);
+            let mass = ctx
+                .db
+                .ufo()
+                .player_id()
+                .filter(player.player_id)
+                .filter_map(|ufo| ctx.db.entity().entity_id().find(ufo.entity_id))
+                .map(|ufo_entity| ufo_entity.mass)
+                .sum();
+            (player, score, mass)
+        })
+        .collect();
+    standings.sort_by(|(_, score_a, mass_a), (_, score_b, mass_b)| {
+        score_b.cmp(score_a).then(mass_b.cmp(mass_a))
+    });
+
+    let middle_rank = (standings.len() as u32).div_ceil(2);
+    for (index, (player, score, mass)) in standings.into_iter().enumerate() {
+        let rank = index as u32 + 1;
+        ctx.db.round_result().try_insert(RoundResult {
+            result_id: 0,
+            arena_id: round.arena_id,
+            round_number: round.round_number,
+            player_id: player.player_id,
+            rank,
+            score,
+            mass,
+            recorded_at: ctx.timestamp,
+        })?;
+
+        let player_id = player.player_id;
+        emit_event(ctx, player_id, GameEvent::RoundFinished { rank });
+        // Bots keep the starting rating
+        if is_bot(ctx, player_id) {
+            continue;
+        }
+
+        // Top half gains rating, bottom half loses it
+        let skill_rating = if rank <= middle_rank {
+            player.skill_rating + (middle_rank - rank + 1) * SKILL_RATING_PER_RANK
+        } else {
+            player.skill_rating.saturating_sub((rank - middle_rank) * SKILL_RATING_PER_RANK)
+        };
+        ctx.db.player().identity().update(Player { skill_rating, ..player });
+    }
+    Ok(())
+}
+
+// Clears every cow and UFO out of the arena and respawns its players fresh
+fn reset_world(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    reset_arena_scores(ctx, arena_id);
+    let players = arena_players(ctx, arena_id);
+    for player in &players {
+        remove_player_ufos(ctx, player.player_id)?;
+    }
+
+    // This also clears out any cows the UFOs had in their holds
+    for cow in ctx.db.cow().arena_id().filter(arena_id) {
+        ctx.db.entity().entity_id().delete(cow.entity_id);
+        ctx.db.cow().entity_id().delete(cow.entity_id);
+    }
+    ctx.db.cow_respawn().arena_id().delete(arena_id);
+    if ctx.db.safe_zone().arena_id().find(arena_id).is_some() {
+        reset_safe_zone(ctx, arena_id)?;
+    }
+
+    for player in players {
+        spawn_player(ctx, player.player_id, arena_id)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, Timestamp};
+use spacetimedb::rand::Rng;
+use std::time::Duration;
+use crate::{
+    entity::cargo::drop_cargo,
+    entity::cow::cow,
+    entity::entity::entity,
+    entity::ufo::ufo,
+    system::round::is_round_in_play,
+    system::system::get_config,
+    util::constants::{
+        PROCESS_GAME_INTERVAL_MS, SAFE_ZONE_HOLD_MS, SAFE_ZONE_INTERVAL_MS, SAFE_ZONE_MASS_LOSS_PER_SEC,
+        SAFE_ZONE_MIN_RADIUS, SAFE_ZONE_SHRINK_FACTOR, SAFE_ZONE_SHRINK_PER_SEC,
+    },
+    util::math::DbVector2,
+};
+
+// The circle players have to stay inside in battle royale arenas. It holds still for a while,
+// then shrinks towards the next, smaller circle inside it.
+#[table(name = safe_zone, public)]
+#[derive(Debug, Clone)]
+pub struct SafeZone {
+    #[primary_key]
+    pub arena_id: u32,
+    pub center: DbVector2,
+    pub radius: f32,
+    // Where the zone is heading next
+    pub target_center: DbVector2,
+    pub target_radius: f32,
+    // The zone holds still until this time
+    pub next_shrink_at: Timestamp,
+}
+
+// Timers
+#[table(name = safe_zone_timer, scheduled(shrink_safe_zone))]
+pub struct SafeZoneTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
+}
+
+impl SafeZone {
+    pub fn contains(&self, point: DbVector2, margin: f32) -> bool {
+        (point - self.center).magnitude() + margin <= self.radius
+    }
+}
+
+// Reducers
+#[reducer]
+pub fn shrink_safe_zone(ctx: &ReducerContext, timer: SafeZoneTimer) -> Result<(), String> {
+    // The zone only closes in while a round is being played
+    if !is_round_in_play(ctx, timer.arena_id) {
+        return Ok(());
+    }
+    let Some(mut zone) = ctx.db.safe_zone().arena_id().find(timer.arena_id) else {
+        return Ok(());
+    };
+    if ctx.timestamp < zone.next_shrink_at || zone.radius <= zone.target_radius {
+        return Ok(());
+    }
+
+    // Move the center along with the radius so both arrive at the same time
+    let step = SAFE_ZONE_SHRINK_PER_SEC * SAFE_ZONE_INTERVAL_MS as f32 / 1000.0;
+    let remaining = zone.radius - zone.target_radius;
+    let progress = (step / remaining).min(1.0);
+    zone.center = zone.center + (zone.target_center - zone.center) * progress;
+    zone.radius -= remaining * progress;
+
+    if progress >= 1.0 {
+        // Pick the next stage, somewhere inside the one just reached
+        let target_radius = (zone.radius * SAFE_ZONE_SHRINK_FACTOR).max(SAFE_ZONE_MIN_RADIUS);
+        let slack = zone.radius - target_radius;
+        let mut rng = ctx.rng();
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = slack * rng.gen_range(0.0f32..1.0).sqrt();
+        zone.target_center = zone.center + DbVector2::new(angle.cos(), angle.sin()) * distance;
+        zone.target_radius = target_radius;
+        zone.next_shrink_at = ctx.timestamp + Duration::from_millis(SAFE_ZONE_HOLD_MS);
+    }
+    ctx.db.safe_zone().arena_id().update(zone);
+    Ok(())
+}
+
+pub fn start_safe_zone(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    reset_safe_zone(ctx, arena_id)?;
+    ctx.db.safe_zone_timer().try_insert(SafeZoneTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(SAFE_ZONE_INTERVAL_MS).into()),
+        arena_id,
+    })?;
+    Ok(())
+}
+
+pub fn stop_safe_zone(ctx: &ReducerContext, arena_id: u32) {
+    for timer in ctx.db.safe_zone_timer().iter().filter(|timer| timer.arena_id == arena_id) {
+        ctx.db.safe_zone_timer().scheduled_id().delete(timer.scheduled_id);
+    }
+    ctx.db.safe_zone().arena_id().delete(arena_id);
+}
+
+// Opens the zone back up to cover the whole map
+pub fn reset_safe_zone(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let bbox = get_config(ctx, arena_id)?.bounds.bounding_box();
+    let center = (bbox.min + bbox.max) / 2.0;
+    let radius = (bbox.max - center).magnitude();
+    let zone = SafeZone {
+        arena_id,
+        center,
+        radius,
+        target_center: center,
+        target_radius: (radius * SAFE_ZONE_SHRINK_FACTOR).max(SAFE_ZONE_MIN_RADIUS),
+        next_shrink_at: ctx.timestamp + Duration::from_millis(SAFE_ZONE_HOLD_MS),
+    };
+    if ctx.db.safe_zone().arena_id().find(arena_id).is_some() {
+        ctx.db.safe_zone().arena_id().update(zone);
+    } else {
+        ctx.db.safe_zone().try_insert(zone)?;
+    }
+    Ok(())
+}
+
+// UFOs caught outside the zone lose mass and their cargo, and cows outside it are taken out of the game. New cows
+// only spawn inside the zone, so the herd refills there.
+pub fn apply_safe_zone(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let Some(zone) = ctx.db.safe_zone().arena_id().find(arena_id) else {
+        return Ok(());
+    };
+    let tick_secs = PROCESS_GAME_INTERVAL_MS as f32 / 1000.0;
+    for mut ufo in ctx.db.ufo().arena_id().filter(arena_id) {
+        let Some(mut ufo_entity) = ctx.db.entity().entity_id().find(ufo.entity_id) else {
+            continue;
+        };
+        if zone.contains(DbVector2::new(ufo_entity.position.x, ufo_entity.position.z), 0.0) {
+            continue;
+        }
+        // The hold spills out, and those cows are lost with the rest outside the zone
+        drop_cargo(ctx, ufo.entity_id)?;
+        ufo.decay_remainder += SAFE_ZONE_MASS_LOSS_PER_SEC * tick_secs;
+        // Every UFO keeps at least 1 mass
+        let lost = (ufo.decay_remainder as u32).min(ufo_entity.mass.saturating_sub(1));
+        if lost > 0 {
+            ufo.decay_remainder -= lost as f32;
+            ufo_entity.mass -= lost;
+            ctx.db.entity().entity_id().update(ufo_entity);
+        }
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+
+    for cow in ctx.db.cow().arena_id().filter(arena_id) {
+        if cow.is_being_abducted {
+            continue;
+        }
+        let Some(cow_entity) = ctx.db.entity().entity_id().find(cow.entity_id) else {
+            continue;
+        };
+        if !zone.contains(DbVector2::new(cow_entity.position.x, cow_entity.position.z), 0.0) {
+            ctx.db.entity().entity_id().delete(cow.entity_id);
+            ctx.db.cow().entity_id().delete(cow.entity_id);
+        }
+    }
+    Ok(())
+}
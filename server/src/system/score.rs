@@ -0,0 +1,157 @@
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table};
+use std::collections::BTreeMap;
+use crate::{
+    system::arena::arena,
+    system::player::player,
+    system::team::{add_team_points, reset_team_scores, team_of},
+    util::constants::LEADERBOARD_SIZE,
+};
+
+// Points a player has earned in their current arena. Unlike mass, score only ever goes up
+// during a round.
+#[table(name = score, public)]
+#[derive(Debug, Clone)]
+pub struct Score {
+    #[primary_key]
+    pub player_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub score: u32,
+    pub cows_abducted: u32,
+}
+
+// The top scorers in each arena, recomputed on a timer. Rows are only rewritten when the
+// standings change, so clients can subscribe to it cheaply.
+#[table(name = leaderboard, public)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub entry_id: u64,
+    #[index(btree)]
+    pub arena_id: u32,
+    // 1 is the top of the board
+    pub rank: u32,
+    pub player_id: u32,
+    pub name: String,
+    pub score: u32,
+}
+
+// Timers
+#[table(name = leaderboard_timer, scheduled(update_leaderboards))]
+pub struct LeaderboardTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// Reducers
+#[reducer]
+pub fn update_leaderboards(ctx: &ReducerContext, _timer: LeaderboardTimer) -> Result<(), String> {
+    for arena in ctx.db.arena().iter() {
+        update_leaderboard(ctx, arena.arena_id)?;
+    }
+    Ok(())
+}
+
+pub fn award_points(ctx: &ReducerContext, player_id: u32, points: u32) {
+    if let Some(mut score) = ctx.db.score().player_id().find(player_id) {
+        score.score += points;
+        ctx.db.score().player_id().update(score);
+    }
+    if let Some(team_id) = team_of(ctx, player_id) {
+        add_team_points(ctx, team_id, points);
+    }
+}
+
+pub fn award_cow_points(ctx: &ReducerContext, player_id: u32, points: u32) {
+    if let Some(mut score) = ctx.db.score().player_id().find(player_id) {
+        score.cows_abducted += 1;
+        ctx.db.score().player_id().update(score);
+    }
+    award_points(ctx, player_id, points);
+}
+
+// Gives the player a fresh score in the arena they just joined
+pub fn reset_score(ctx: &ReducerContext, player_id: u32, arena_id: Option<u32>) -> Result<(), String> {
+    ctx.db.score().player_id().delete(player_id);
+    if let Some(arena_id) = arena_id {
+        ctx.db.score().try_insert(Score {
+            player_id,
+            arena_id,
+            score: 0,
+            cows_abducted: 0,
+        })?;
+    }
+    Ok(())
+}
+
+// Zeroes everyone's score for a new round
+pub fn reset_arena_scores(ctx: &ReducerContext, arena_id: u32) {
+    reset_team_scores(ctx, arena_id);
+    for score in ctx.db.score().arena_id().filter(arena_id) {
+        ctx.db.score().player_id().update(Score {
+            score: 0,
+            cows_abducted: 0,
+            ..score
+        });
+    }
+}
+
+pub fn clear_scores(ctx: &ReducerContext, arena_id: u32) {
+    ctx.db.score().arena_id().delete(arena_id);
+    ctx.db.leaderboard().arena_id().delete(arena_id);
+}
+
+fn update_leaderboard(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let mut scores: Vec<Score> = ctx.db.score().arena_id().filter(arena_id).collect();
+    // Ties go to whoever joined first
+    scores.sort_by(|a, b| b.score.cmp(&a.score).then(a.player_id.cmp(&b.player_id)));
+
+    let mut existing: BTreeMap<u32, LeaderboardEntry> = ctx
+        .db
+        .leaderboard()
+        .arena_id()
+        .filter(arena_id)
+        .map(|entry| (entry.rank, entry))
+        .collect();
+
+    for (index, score) in scores.into_iter().take(LEADERBOARD_SIZE).enumerate() {
+        let rank = index as u32 + 1;
+        let name = ctx
+            .db
+            .player()
+            .player_id()
+            .find(score.player_id)
+            .map_or_else(String::new, |player| player.name);
+        let entry = LeaderboardEntry {
+            entry_id: 0,
+            arena_id,
+            rank,
+            player_id: score.player_id,
+            name,
+            score: score.score,
+        };
+        match existing.remove(&rank) {
+            Some(current) => {
+                let entry = LeaderboardEntry {
+                    entry_id: current.entry_id,
+                    ..entry
+                };
+                if entry != current {
+                    ctx.db.leaderboard().entry_id().update(entry);
+                }
+            }
+            None => {
+                ctx.db.leaderboard().try_insert(entry)?;
+            }
+        }
+    }
+
+    // The board got shorter
+    for entry in existing.into_values() {
+        ctx.db.leaderboard().entry_id().delete(entry.entry_id);
+    }
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use spacetimedb::{table, Identity, ReducerContext, Table, Timestamp};
+use crate::{
+    entity::cow::CowKind,
+    system::player::player,
+};
+
+// Lifetime totals for everyone who has ever played in an arena. Unlike `player`, rows are kept
+// when the player disconnects.
+#[table(name = player_stats, public)]
+#[derive(Debug, Clone)]
+pub struct PlayerStats {
+    #[primary_key]
+    pub identity: Identity,
+    pub regular_cows_abducted: u32,
+    pub golden_cows_abducted: u32,
+    pub peak_mass: u32,
+    pub total_play_time_ms: u64,
+    pub sessions_played: u32,
+    // Set while the player is in an arena
+    pub session_started_at: Option<Timestamp>,
+}
+
+pub fn start_session(ctx: &ReducerContext, identity: Identity) {
+    match ctx.db.player_stats().identity().find(identity) {
+        Some(stats) => {
+            ctx.db.player_stats().identity().update(PlayerStats {
+                sessions_played: stats.sessions_played + 1,
+                session_started_at: Some(ctx.timestamp),
+                ..stats
+            });
+        }
+        None => {
+            ctx.db.player_stats().insert(PlayerStats {
+                identity,
+                regular_cows_abducted: 0,
+                golden_cows_abducted: 0,
+                peak_mass: 0,
+                total_play_time_ms: 0,
+                sessions_played: 1,
+                session_started_at: Some(ctx.timestamp),
+            });
+        }
+    }
+}
+
+pub fn end_session(ctx: &ReducerContext, identity: Identity) {
+    update_stats(ctx, identity, |stats| {
+        if let Some(started_at) = stats.session_started_at.take() {
+            let played = ctx.timestamp.duration_since(started_at).unwrap_or_default();
+            stats.total_play_time_ms += played.as_millis() as u64;
+        }
+    });
+}
+
+pub fn record_abduction(ctx: &ReducerContext, player_id: u32, kind: CowKind) {
+    update_player_stats(ctx, player_id, |stats| match kind {
+        CowKind::Regular => stats.regular_cows_abducted += 1,
+        CowKind::Golden => stats.golden_cows_abducted += 1,
+    });
+}
+
+// A UFO only grows when it banks its cargo
+pub fn record_mass(ctx: &ReducerContext, player_id: u32, mass: u32) {
+    update_player_stats(ctx, player_id, |stats| stats.peak_mass = stats.peak_mass.max(mass));
+}
+
+fn update_player_stats(ctx: &ReducerContext, player_id: u32, update: impl FnOnce(&mut PlayerStats)) {
+    if let Some(player) = ctx.db.player().player_id().find(player_id) {
+        update_stats(ctx, player.identity, update);
+    }
+}
+
+fn update_stats(ctx: &ReducerContext, identity: Identity, update: impl FnOnce(&mut PlayerStats)) {
+    if let Some(mut stats) = ctx.db.player_stats().identity().find(identity) {
+        update(&mut stats);
+        ctx.db.player_stats().identity().update(stats);
+    }
+}
@@ -1,31 +1,80 @@
 use spacetimedb::rand::Rng;
 use spacetimedb::Identity;
-use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table};
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, SpacetimeType, Table};
 use spacetimedb::{table, Timestamp};
 use std::time::Duration;
 
-use crate::entity::cow::cow;
+use crate::entity::cargo::{bank_cargo, cargo_mass, drop_cargo, is_hold_full, store_cow};
+use crate::entity::cow::{cow, release_abducted_cows};
+use crate::entity::obstacle::{is_shadowed, obstacle, Obstacle};
+use crate::system::arena::{create_arena_with_map, move_player_to_arena, DEFAULT_ARENA_CAPACITY, DEFAULT_REGION};
+use crate::system::matchmaking::{enqueue_player, matchmaking_queue, matchmaking_timer, MatchmakingTimer};
+use crate::system::map::{embedded_map, spawn_point, DEFAULT_MAP};
+use crate::system::achievement::{emit_event, seed_achievements, GameEvent};
+use crate::system::round::is_round_in_play;
+use crate::system::score::{leaderboard_timer, LeaderboardTimer};
+use crate::system::bot::{bot_timer, BotDifficulty, BotTimer};
+use crate::system::control_zone::{score_control_zone, start_control_zone, stop_control_zone};
+use crate::system::safe_zone::{apply_safe_zone, safe_zone, start_safe_zone, stop_safe_zone};
+use crate::system::team::{assign_team, clear_teams, setup_teams, team_of};
+use crate::system::stats::record_abduction;
 use crate::entity::ufo::mass_to_ufo_size;
-use crate::util::constants::{START_PLAYER_HEIGHT, WORLD_SIZE};
+use crate::util::bounds::WorldBounds;
+use crate::util::constants::{
+    BEAM_DRAIN_PER_LIFTED_MASS_PER_SEC, BEAM_DRAIN_PER_SEC, BEAM_RECHARGE_PER_SEC,
+    BOOST_DURATION_MS, BOOST_SPEED_MULTIPLIER, BOT_THINK_INTERVAL_MS, COW_CONTEST_DRIFT_PER_SEC,
+    COW_LIFT_PER_SEC, LEADERBOARD_INTERVAL_MS, MATCHMAKING_INTERVAL_MS, MAX_BEAM_ENERGY,
+    PROCESS_GAME_INTERVAL_MS, START_SKILL_RATING, UFO_ACCELERATION, UFO_CLIMB_PER_SEC,
+    UFO_DRAG_PER_SEC, UFO_RESTITUTION, UFO_SPAWN_HEIGHT,
+};
 use crate::util::math::DbVector2;
-use crate::util::util::is_cow_in_beam;
+use crate::util::util::{altitude_to_beam_radius, altitude_to_speed_factor, beam_pull, is_cow_in_beam};
 use crate::{
-    entity::cow::{
-        change_cow_direction_timer, move_all_cows_timer, spawn_cows_timer, ChangeCowDirectionTimer,
-        MoveAllCowsTimer, SpawnCowsTimer,
-    },
     entity::entity::{entity, Entity},
     entity::ufo::{ufo, Ufo},
     system::player::{player, validate_message, validate_name, Player},
     util::math::DbVector3,
-    util::util::mass_to_max_move_speed,
+    util::util::mass_to_max_ufo_velocity,
 };
 
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum GameMode {
+    FreeForAll,
+    // The playable area shrinks over the course of each round
+    BattleRoyale,
+    // Players fight over a control zone that scores points for whoever holds it
+    KingOfTheHill,
+}
+
+// One row per arena
 #[table(name = config, public)]
 pub struct Config {
     #[primary_key]
-    pub id: u32,
-    pub world_size: u64,
+    pub arena_id: u32,
+    pub mode: GameMode,
+    // Players are split into teams that share a score and don't steal each other's cows. Works
+    // with any mode.
+    pub teams: bool,
+    pub bounds: WorldBounds,
+    pub map_name: String,
+    // Seed the current map was generated from, if it was generated
+    pub map_seed: Option<u64>,
+    // UFOs heavier than the threshold lose this fraction of the excess mass every second
+    pub mass_decay_threshold: u32,
+    pub mass_decay_per_sec: f32,
+    // How low and high UFOs may fly
+    pub min_altitude: f32,
+    pub max_altitude: f32,
+    // Bots top the arena up to this many players while any humans are in it
+    pub bot_fill: u32,
+    pub bot_difficulty: BotDifficulty,
+}
+
+// Identities allowed to manage arenas and maps. Whoever published the module is added on init.
+#[table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
 }
 
 #[table(name = message, public)]
@@ -42,33 +91,33 @@ pub struct ProcessGameTimer {
     #[auto_inc]
     pub scheduled_id: u64,
     pub scheduled_at: ScheduleAt,
+    pub arena_id: u32,
 }
 
 // Reducers
 #[reducer(init)]
 pub fn init(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("Initializing...");
-    ctx.db.config().try_insert(Config {
-        id: 0,
-        world_size: WORLD_SIZE,
-    })?;
-    ctx.db.spawn_cows_timer().try_insert(SpawnCowsTimer {
+    ctx.db.admin().try_insert(Admin { identity: ctx.sender })?;
+    seed_achievements(ctx)?;
+    create_arena_with_map(
+        ctx,
+        "Default".to_string(),
+        DEFAULT_REGION.to_string(),
+        DEFAULT_ARENA_CAPACITY,
+        &embedded_map(DEFAULT_MAP)?,
+    )?;
+    ctx.db.matchmaking_timer().try_insert(MatchmakingTimer {
         scheduled_id: 0,
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(500).into()),
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(MATCHMAKING_INTERVAL_MS).into()),
     })?;
-    ctx.db.process_game_timer().try_insert(ProcessGameTimer {
+    ctx.db.leaderboard_timer().try_insert(LeaderboardTimer {
         scheduled_id: 0,
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(50).into()),
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(LEADERBOARD_INTERVAL_MS).into()),
     })?;
-    ctx.db
-        .change_cow_direction_timer()
-        .try_insert(ChangeCowDirectionTimer {
-            scheduled_id: 0,
-            scheduled_at: ScheduleAt::Interval(Duration::from_millis(1000).into()),
-        })?;
-    ctx.db.move_all_cows_timer().try_insert(MoveAllCowsTimer {
+    ctx.db.bot_timer().try_insert(BotTimer {
         scheduled_id: 0,
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(50).into()),
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(BOT_THINK_INTERVAL_MS).into()),
     })?;
 
     Ok(())
@@ -77,11 +126,117 @@ pub fn init(ctx: &ReducerContext) -> Result<(), String> {
 #[reducer]
 pub fn process_game(
     ctx: &ReducerContext,
-    _process_game_timer: ProcessGameTimer,
+    process_game_timer: ProcessGameTimer,
 ) -> Result<(), String> {
-    move_all_players(ctx).expect("TODO: panic message");
-    check_all_beams(ctx).expect("TODO: panic message");
-    process_abductions(ctx).expect("TODO: panic message");
+    let arena_id = process_game_timer.arena_id;
+    let in_play = is_round_in_play(ctx, arena_id);
+    move_all_players(ctx, arena_id).expect("TODO: panic message");
+    if in_play {
+        score_control_zone(ctx, arena_id);
+    }
+    resolve_ufo_collisions(ctx, arena_id)?;
+    update_beam_energy(ctx, arena_id);
+    decay_mass(ctx, arena_id)?;
+    // UFOs can fly around in the lobby, but only abduct while a round is being played
+    if in_play {
+        check_all_beams(ctx, arena_id).expect("TODO: panic message");
+        process_abductions(ctx, arena_id).expect("TODO: panic message");
+        bank_cargo(ctx, arena_id);
+        apply_safe_zone(ctx, arena_id)?;
+    }
+    Ok(())
+}
+
+pub fn get_config(ctx: &ReducerContext, arena_id: u32) -> Result<Config, String> {
+    ctx.db
+        .config()
+        .arena_id()
+        .find(arena_id)
+        .ok_or("Config not found".to_string())
+}
+
+pub fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        return Err("Only an admin can do that".to_string());
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn set_game_mode(ctx: &ReducerContext, arena_id: u32, mode: GameMode) -> Result<(), String> {
+    require_admin(ctx)?;
+    let config = get_config(ctx, arena_id)?;
+    if config.mode == mode {
+        return Ok(());
+    }
+    log::info!("Arena {} switching to {:?}", arena_id, mode);
+    let previous_mode = config.mode;
+    ctx.db.config().arena_id().update(Config { mode, ..config });
+    match previous_mode {
+        GameMode::FreeForAll => {}
+        GameMode::BattleRoyale => stop_safe_zone(ctx, arena_id),
+        GameMode::KingOfTheHill => stop_control_zone(ctx, arena_id),
+    }
+    match mode {
+        GameMode::FreeForAll => {}
+        GameMode::BattleRoyale => start_safe_zone(ctx, arena_id)?,
+        GameMode::KingOfTheHill => start_control_zone(ctx, arena_id)?,
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn set_teams(ctx: &ReducerContext, arena_id: u32, teams: bool) -> Result<(), String> {
+    require_admin(ctx)?;
+    let config = get_config(ctx, arena_id)?;
+    if config.teams == teams {
+        return Ok(());
+    }
+    log::info!("Arena {} teams {}", arena_id, if teams { "on" } else { "off" });
+    ctx.db.config().arena_id().update(Config { teams, ..config });
+    if teams {
+        setup_teams(ctx, arena_id)
+    } else {
+        clear_teams(ctx, arena_id);
+        Ok(())
+    }
+}
+
+#[reducer]
+pub fn set_altitude_range(ctx: &ReducerContext, arena_id: u32, min_altitude: f32, max_altitude: f32) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !min_altitude.is_finite() || !max_altitude.is_finite() {
+        return Err("Altitudes must be numbers".to_string());
+    }
+    if min_altitude <= 0.0 || max_altitude < min_altitude {
+        return Err("Altitudes must be positive with the minimum below the maximum".to_string());
+    }
+    let config = get_config(ctx, arena_id)?;
+    ctx.db.config().arena_id().update(Config {
+        min_altitude,
+        max_altitude,
+        ..config
+    });
+    // Bring every UFO back into the new range
+    for mut ufo in ctx.db.ufo().arena_id().filter(arena_id) {
+        ufo.target_altitude = ufo.target_altitude.clamp(min_altitude, max_altitude);
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn set_mass_decay(ctx: &ReducerContext, arena_id: u32, threshold: u32, per_sec: f32) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !(0.0..=1.0).contains(&per_sec) {
+        return Err("Mass decay must be between 0 and 1 per second".to_string());
+    }
+    let config = get_config(ctx, arena_id)?;
+    ctx.db.config().arena_id().update(Config {
+        mass_decay_threshold: threshold,
+        mass_decay_per_sec: per_sec,
+        ..config
+    });
     Ok(())
 }
 
@@ -96,6 +251,9 @@ pub fn connect(ctx: &ReducerContext) {
             name: ctx.sender.to_string(),
             identity: ctx.sender,
             player_id: 0,
+            arena_id: None,
+            skill_rating: START_SKILL_RATING,
+            team_id: None,
         });
     }
 }
@@ -106,16 +264,12 @@ pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
         .db
         .player()
         .identity()
-        .find(&ctx.sender)
+        .find(ctx.sender)
         .ok_or("Player not found")?;
-    let player_id = player.player_id;
-    ctx.db.player().identity().delete(&ctx.sender);
-
-    for ufo in ctx.db.ufo().player_id().filter(&player_id) {
-        log::info!("Deleting UFO");
-        ctx.db.entity().entity_id().delete(&ufo.entity_id);
-        ctx.db.ufo().entity_id().delete(&ufo.entity_id);
-    }
+    // Takes the player's UFOs out of the world and frees their arena slot
+    ctx.db.matchmaking_queue().identity().delete(ctx.sender);
+    move_player_to_arena(ctx, player, None)?;
+    ctx.db.player().identity().delete(ctx.sender);
 
     Ok(())
 }
@@ -139,36 +293,65 @@ pub fn enter_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
     let player_id = player.player_id;
     player.name = validate_name(name)?;
     ctx.db.player().identity().update(player);
-    spawn_player(ctx, player_id)?;
+
+    // Players already in an arena respawn there, everyone else waits for the matchmaker
+    let player = ctx.db.player().identity().find(ctx.sender).ok_or("Player not found")?;
+    match player.arena_id {
+        Some(arena_id) => {
+            assign_team(ctx, player_id)?;
+            spawn_player(ctx, player_id, arena_id)?
+        }
+        None => enqueue_player(ctx, &player, DEFAULT_REGION.to_string())?,
+    }
 
     Ok(())
 }
 
-fn spawn_player(ctx: &ReducerContext, player_id: u32) -> Result<(), String> {
-    let world_size = ctx
-        .db
-        .config()
-        .id()
-        .find(0)
-        .ok_or("Config not found")?
-        .world_size;
+pub fn spawn_player(ctx: &ReducerContext, player_id: u32, arena_id: u32) -> Result<(), String> {
+    let config = get_config(ctx, arena_id)?;
+    let bounds = config.bounds;
     let mut rng = ctx.rng();
-    let x = rng.gen_range(0.0..world_size as f32);
-    let y: f32 = mass_to_ufo_size(100);
-    let z = rng.gen_range(0.0..world_size as f32);
-    spawn_player_at(ctx, player_id, 1, DbVector3 { x, y, z }, ctx.timestamp)?;
+    let y: f32 = UFO_SPAWN_HEIGHT.clamp(config.min_altitude, config.max_altitude);
+    let zone = ctx.db.safe_zone().arena_id().find(arena_id);
+    let spawn_points: Vec<DbVector3> = ctx
+        .db
+        .spawn_point()
+        .arena_id()
+        .filter(arena_id)
+        .map(|point| point.position)
+        // Once the safe zone has closed in, only spawn inside it
+        .filter(|position| {
+            zone.as_ref()
+                .is_none_or(|zone| zone.contains(DbVector2::new(position.x, position.z), 0.0))
+        })
+        .collect();
+    let (x, z) = if spawn_points.is_empty() {
+        let point = match zone {
+            Some(zone) => bounds.constrain_circle(zone.center, mass_to_ufo_size(1)),
+            None => bounds
+                .random_point(&mut rng, mass_to_ufo_size(1))
+                .ok_or("No room to spawn a UFO")?,
+        };
+        (point.x, point.y)
+    } else {
+        let point = spawn_points[rng.gen_range(0..spawn_points.len())];
+        (point.x, point.z)
+    };
+    spawn_player_at(ctx, player_id, arena_id, 1, DbVector3 { x, y, z }, ctx.timestamp)?;
     Ok(())
 }
 
 fn spawn_player_at(
     ctx: &ReducerContext,
     player_id: u32,
+    arena_id: u32,
     mass: u32,
     position: DbVector3,
     timestamp: Timestamp,
 ) -> Result<Entity, String> {
     let entity = ctx.db.entity().try_insert(Entity {
         entity_id: 0,
+        arena_id,
         position,
         mass,
     })?;
@@ -176,14 +359,20 @@ fn spawn_player_at(
     ctx.db.ufo().try_insert(Ufo {
         entity_id: entity.entity_id,
         player_id,
+        arena_id,
         direction: DbVector3 {
             x: 0.0,
             y: 0.0,
             z: 0.0,
         },
         speed: 0.0,
+        velocity: DbVector3::new(0.0, 0.0, 0.0),
+        target_altitude: position.y,
         last_split_time: timestamp,
+        last_boost_time: Timestamp::UNIX_EPOCH,
         beam_on: false,
+        beam_energy: MAX_BEAM_ENERGY,
+        decay_remainder: 0.0,
         abducting: false,
         abducted_entity: None,
     })?;
@@ -191,117 +380,264 @@ fn spawn_player_at(
     Ok(entity)
 }
 
-#[reducer]
-fn move_all_players(ctx: &ReducerContext) -> Result<(), String> {
-    let world_size = ctx
-        .db
-        .config()
-        .id()
-        .find(0)
-        .ok_or("Config not found")?
-        .world_size;
+// Deletes all of a player's UFOs, dropping anything they were abducting or carrying
+pub fn remove_player_ufos(ctx: &ReducerContext, player_id: u32) -> Result<(), String> {
+    for ufo in ctx.db.ufo().player_id().filter(player_id) {
+        log::info!("Deleting UFO");
+        release_abducted_cows(ctx, ufo.arena_id, ufo.entity_id);
+        drop_cargo(ctx, ufo.entity_id)?;
+        ctx.db.entity().entity_id().delete(ufo.entity_id);
+        ctx.db.ufo().entity_id().delete(ufo.entity_id);
+    }
+    Ok(())
+}
+
+fn move_all_players(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let config = get_config(ctx, arena_id)?;
+    let bounds = config.bounds;
+
+    let tick_secs = PROCESS_GAME_INTERVAL_MS as f32 / 1000.0;
 
     // Handle player input
-    for ufo in ctx.db.ufo().iter() {
+    for mut ufo in ctx.db.ufo().arena_id().filter(arena_id) {
         // If a UFO is abducting an enemy, can't move
         if ufo.beam_on && ufo.abducting {
+            if ufo.velocity.sqr_magnitude() > 0.0 {
+                ufo.velocity = DbVector3::new(0.0, 0.0, 0.0);
+                ctx.db.ufo().entity_id().update(ufo);
+            }
             continue;
         }
 
-        let ufo_entity = ctx.db.entity().entity_id().find(&ufo.entity_id);
+        let ufo_entity = ctx.db.entity().entity_id().find(ufo.entity_id);
         // This can happen if a circle is eaten by another circle
-        if !ufo_entity.is_some() {
+        if ufo_entity.is_none() {
             continue;
         }
 
         let mut ufo_entity = ufo_entity.unwrap();
         let ufo_size = mass_to_ufo_size(ufo_entity.mass);
-        let direction = ufo.direction * ufo.speed / 60.0;
-        let new_pos = ufo_entity.position + direction * mass_to_max_move_speed(ufo_entity.mass);
-        let min = ufo_size;
-        let max = world_size as f32 - ufo_size;
-        ufo_entity.position.x = new_pos.x.clamp(min, max);
-        ufo_entity.position.z = new_pos.z.clamp(min, max);
+
+        // Heavier UFOs take longer to get going and longer to stop. Cows in the hold weigh the
+        // UFO down until they're delivered.
+        let loaded_mass = ufo_entity.mass + cargo_mass(ctx, ufo.entity_id);
+        let heft = (loaded_mass as f32).sqrt().max(1.0);
+        // Flying low is faster
+        let altitude_factor = altitude_to_speed_factor(ufo_entity.position.y);
+        let thrust = if ufo.speed > 0.0 { ufo.direction * ufo.speed } else { DbVector3::new(0.0, 0.0, 0.0) };
+        let mut velocity = ufo.velocity + thrust * (UFO_ACCELERATION * altitude_factor / heft * tick_secs);
+        velocity = velocity * (1.0 - UFO_DRAG_PER_SEC / heft * tick_secs).max(0.0);
+        velocity.y = 0.0;
+        let mut max_speed = mass_to_max_ufo_velocity(loaded_mass) * altitude_factor;
+        if is_boosting(&ufo, ctx.timestamp) {
+            max_speed *= BOOST_SPEED_MULTIPLIER;
+        }
+        if velocity.magnitude() > max_speed {
+            velocity = velocity.normalized() * max_speed;
+        }
+
+        let old_pos = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+        let new_pos = old_pos + DbVector2::new(velocity.x, velocity.z) * tick_secs;
+        // Keep the whole UFO inside the walls, not just its center
+        let ground_pos = bounds.constrain_circle(new_pos, ufo_size);
+        if (ground_pos - new_pos).sqr_magnitude() > 0.0 {
+            // Hitting a wall kills the velocity going into it
+            let moved = (ground_pos - old_pos) / tick_secs;
+            velocity = DbVector3::new(moved.x, 0.0, moved.y);
+        }
+        ufo_entity.position.x = ground_pos.x;
+        ufo_entity.position.z = ground_pos.y;
+
+        // Climb or descend towards the requested altitude
+        let target_altitude = ufo.target_altitude.clamp(config.min_altitude, config.max_altitude);
+        let climb = UFO_CLIMB_PER_SEC * tick_secs;
+        ufo_entity.position.y += (target_altitude - ufo_entity.position.y).clamp(-climb, climb);
         ctx.db.entity().entity_id().update(ufo_entity);
+        ufo.velocity = velocity;
+        ctx.db.ufo().entity_id().update(ufo);
     }
 
     Ok(())
 }
 
-#[reducer]
-fn check_all_beams(ctx: &ReducerContext) -> Result<(), String> {
-    for mut ufo in ctx.db.ufo().iter() {
-        match ctx.db.entity().entity_id().find(&ufo.entity_id) {
-            None => {}
-            Some(ufo_entity) => {
-                if ufo.beam_on {
-                    for mut cow in ctx.db.cow().iter() {
-                        // If a cow is directly below ufo, it gets abducted
-                        match ctx.db.entity().entity_id().find(&cow.entity_id) {
-                            None => {}
-                            Some(mut cow_entity) => {
-                                let cow_pos = DbVector2 {
-                                    x: cow_entity.position.x,
-                                    y: cow_entity.position.z,
-                                };
-                                let ufo_pos = DbVector2 {
-                                    x: ufo_entity.position.x,
-                                    y: ufo_entity.position.z,
-                                };
-                                if is_cow_in_beam(cow_pos, ufo_pos) {
-                                    cow_entity.position.x = ufo_pos.x;
-                                    cow_entity.position.z = ufo_pos.y;
-                                    let new_cow_entity = cow_entity.clone();
-                                    cow.is_being_abducted = true;
-                                    cow.abducted_by =
-                                        ctx.db.entity().entity_id().find(&ufo.entity_id);
-                                    ctx.db.cow().entity_id().update(cow);
-                                    ctx.db.entity().entity_id().update(new_cow_entity);
-                                    ufo.abducted_entity = Option::from(cow_entity);
-                                }
-                            }
-                        }
-                    }
-                    ctx.db.ufo().entity_id().update(ufo);
-                } else {
-                    // Release all cows this player is holding
-                    for cow in ctx.db.cow().iter() {
-                        let mut new_cow = cow;
-                        match new_cow.abducted_by {
-                            None => {}
-                            Some(it) => {
-                                if it.entity_id == ufo_entity.entity_id {
-                                    new_cow.is_being_abducted = false;
-                                    new_cow.abducted_by = None;
-                                    match ctx.db.entity().entity_id().find(&new_cow.entity_id) {
-                                        None => {}
-                                        Some(mut new_entity) => {
-                                            new_entity.position.y = 0.125f32;
-                                            ctx.db.entity().entity_id().update(new_entity);
-                                        }
-                                    }
-                                    ctx.db.cow().entity_id().update(new_cow);
-                                }
-                            }
-                        }
-                    }
-                }
+pub fn is_boosting(ufo: &Ufo, now: Timestamp) -> bool {
+    now < ufo.last_boost_time + Duration::from_millis(BOOST_DURATION_MS)
+}
+
+// Big UFOs slowly shrink back towards the decay threshold, so leaders have to keep abducting
+fn decay_mass(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let config = get_config(ctx, arena_id)?;
+    let tick_secs = PROCESS_GAME_INTERVAL_MS as f32 / 1000.0;
+    for mut ufo in ctx.db.ufo().arena_id().filter(arena_id) {
+        let Some(mut ufo_entity) = ctx.db.entity().entity_id().find(ufo.entity_id) else {
+            continue;
+        };
+        if ufo_entity.mass <= config.mass_decay_threshold {
+            continue;
+        }
+        let excess = ufo_entity.mass - config.mass_decay_threshold;
+        ufo.decay_remainder += excess as f32 * config.mass_decay_per_sec * tick_secs;
+        let lost = (ufo.decay_remainder as u32).min(excess);
+        if lost > 0 {
+            ufo.decay_remainder -= lost as f32;
+            ufo_entity.mass -= lost;
+            emit_event(ctx, ufo.player_id, GameEvent::MassReached(ufo_entity.mass));
+            ctx.db.entity().entity_id().update(ufo_entity);
+        }
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+    Ok(())
+}
+
+// Beams drain energy while on, faster for every bit of mass being lifted, and recharge while off.
+// A beam that runs dry shuts off and drops whatever it was holding.
+fn update_beam_energy(ctx: &ReducerContext, arena_id: u32) {
+    let tick_secs = PROCESS_GAME_INTERVAL_MS as f32 / 1000.0;
+    for mut ufo in ctx.db.ufo().arena_id().filter(arena_id) {
+        if !ufo.beam_on {
+            if ufo.beam_energy < MAX_BEAM_ENERGY {
+                ufo.beam_energy = (ufo.beam_energy + BEAM_RECHARGE_PER_SEC * tick_secs).min(MAX_BEAM_ENERGY);
+                ctx.db.ufo().entity_id().update(ufo);
+            }
+            continue;
+        }
+
+        let lifted_mass: u32 = ctx
+            .db
+            .cow()
+            .arena_id()
+            .filter(arena_id)
+            .filter(|cow| cow.abducted_by.as_ref().is_some_and(|by| by.entity_id == ufo.entity_id))
+            .filter_map(|cow| ctx.db.entity().entity_id().find(cow.entity_id))
+            .map(|cow_entity| cow_entity.mass)
+            .sum();
+        let drain = BEAM_DRAIN_PER_SEC + lifted_mass as f32 * BEAM_DRAIN_PER_LIFTED_MASS_PER_SEC;
+        ufo.beam_energy = (ufo.beam_energy - drain * tick_secs).max(0.0);
+        if ufo.beam_energy <= 0.0 {
+            ufo.beam_on = false;
+            ufo.abducted_entity = None;
+            release_abducted_cows(ctx, arena_id, ufo.entity_id);
+        }
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+}
+
+// Hands each beamed cow to the UFO pulling on it hardest. While several beams overlap on a cow
+// it drifts towards the strongest one instead of being lifted.
+fn check_all_beams(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let obstacles: Vec<Obstacle> = ctx.db.obstacle().arena_id().filter(arena_id).collect();
+    let tick_secs = PROCESS_GAME_INTERVAL_MS as f32 / 1000.0;
+    let mut beams: Vec<(Ufo, Entity, Option<u32>)> = Vec::new();
+    for ufo in ctx.db.ufo().arena_id().filter(arena_id) {
+        let Some(ufo_entity) = ctx.db.entity().entity_id().find(ufo.entity_id) else {
+            continue;
+        };
+        if ufo.beam_on {
+            // A full hold can't take on any more cows
+            if !is_hold_full(ctx, ufo.entity_id, ufo_entity.mass) {
+                let team_id = team_of(ctx, ufo.player_id);
+                beams.push((ufo, ufo_entity, team_id));
+            }
+        } else {
+            // Release all cows this player is holding
+            release_abducted_cows(ctx, arena_id, ufo_entity.entity_id);
+        }
+    }
+    if beams.is_empty() {
+        return Ok(());
+    }
+
+    for mut cow in ctx.db.cow().arena_id().filter(arena_id) {
+        let Some(mut cow_entity) = ctx.db.entity().entity_id().find(cow.entity_id) else {
+            continue;
+        };
+        let cow_pos = DbVector2::new(cow_entity.position.x, cow_entity.position.z);
+        // Cows hiding under barns, silos and trees are safe
+        if is_shadowed(&obstacles, cow_pos) {
+            if !cow.contested_by.is_empty() {
+                cow.contested_by.clear();
+                ctx.db.cow().entity_id().update(cow);
             }
+            continue;
+        }
+        // Teammates don't steal cows from each other
+        let holder_team = cow.abducted_by.as_ref().and_then(|holder| {
+            ctx.db.ufo().entity_id().find(holder.entity_id).and_then(|ufo| team_of(ctx, ufo.player_id))
+        });
+        let holder_id = cow.abducted_by.as_ref().map(|holder| holder.entity_id);
+        let mut pulls: Vec<(f32, &Entity)> = beams
+            .iter()
+            .filter(|(ufo, _, team_id)| {
+                holder_team.is_none() || *team_id != holder_team || holder_id == Some(ufo.entity_id)
+            })
+            .filter_map(|(_, ufo_entity, _)| {
+                let ufo_pos = DbVector2::new(ufo_entity.position.x, ufo_entity.position.z);
+                let beam_radius = altitude_to_beam_radius(ufo_entity.position.y);
+                is_cow_in_beam(cow_pos, ufo_pos, beam_radius)
+                    .then(|| (beam_pull(ufo_entity.mass, (cow_pos - ufo_pos).magnitude()), ufo_entity))
+            })
+            .collect();
+        if pulls.is_empty() {
+            // The other beams moved off, so whoever has the cow can lift it
+            if !cow.contested_by.is_empty() {
+                cow.contested_by.clear();
+                ctx.db.cow().entity_id().update(cow);
+            }
+            continue;
+        }
+        pulls.sort_by(|(pull_a, _), (pull_b, _)| pull_b.total_cmp(pull_a));
+
+        let (strongest, winner) = pulls[0];
+        let winner_pos = DbVector2::new(winner.position.x, winner.position.z);
+        let new_pos = if pulls.len() == 1 {
+            cow.contested_by.clear();
+            winner_pos
+        } else {
+            // The bigger the lead, the faster the cow drifts over
+            let total: f32 = pulls.iter().map(|(pull, _)| pull).sum();
+            let lead = (strongest - pulls[1].0) / total;
+            let offset = winner_pos - cow_pos;
+            let drift = (COW_CONTEST_DRIFT_PER_SEC * lead * tick_secs).min(offset.magnitude());
+            cow.contested_by = pulls.iter().map(|(_, ufo_entity)| ufo_entity.entity_id).collect();
+            if drift > 0.0 { cow_pos + offset.normalized() * drift } else { cow_pos }
+        };
+        cow_entity.position.x = new_pos.x;
+        cow_entity.position.z = new_pos.y;
+        // The UFO that held the cow until now lets go of it
+        if let Some(mut previous) = holder_id
+            .filter(|holder_id| *holder_id != winner.entity_id)
+            .and_then(|holder_id| ctx.db.ufo().entity_id().find(holder_id))
+        {
+            if previous.abducted_entity.as_ref().is_some_and(|held| held.entity_id == cow.entity_id) {
+                previous.abducted_entity = None;
+                ctx.db.ufo().entity_id().update(previous);
+            }
+        }
+        cow.is_being_abducted = true;
+        cow.abducted_by = Some(winner.clone());
+        ctx.db.cow().entity_id().update(cow);
+        ctx.db.entity().entity_id().update(cow_entity.clone());
+        if let Some(mut ufo) = ctx.db.ufo().entity_id().find(winner.entity_id) {
+            ufo.abducted_entity = Some(cow_entity);
+            ctx.db.ufo().entity_id().update(ufo);
         }
     }
     Ok(())
 }
 
-#[reducer]
-fn process_abductions(ctx: &ReducerContext) -> Result<(), String> {
-    for cow in ctx.db.cow().iter() {
-        if cow.is_being_abducted && cow.abducted_by.is_some() {
-            match ctx.db.entity().entity_id().find(&cow.entity_id) {
+// Lifts beamed cows at a steady rate, so the higher the UFO the longer it takes
+fn process_abductions(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let lift = COW_LIFT_PER_SEC * PROCESS_GAME_INTERVAL_MS as f32 / 1000.0;
+    for cow in ctx.db.cow().arena_id().filter(arena_id) {
+        // Contested cows stay put until one beam wins out
+        if cow.is_being_abducted && cow.abducted_by.is_some() && cow.contested_by.is_empty() {
+            match ctx.db.entity().entity_id().find(cow.entity_id) {
                 None => {}
                 Some(mut cow_entity) => {
                     match cow.abducted_by {
                         None => {
                             cow_entity.position.y = 0.125f32;
+                            ctx.db.entity().entity_id().update(cow_entity);
                         }
                         Some(ref ufo) => {
                             log::info!(
@@ -311,31 +647,39 @@ fn process_abductions(ctx: &ReducerContext) -> Result<(), String> {
                             if cow_entity.position.y >= ufo.position.y {
                                 // Update ufo and ufo entity
                                 let mut ufo =
-                                    ctx.db.ufo().entity_id().find(&ufo.entity_id).unwrap();
+                                    ctx.db.ufo().entity_id().find(ufo.entity_id).unwrap();
                                 ufo.abducting = false;
-                                let mut ufo_entity = ctx
+                                ufo.abducted_entity = None;
+                                let ufo_entity = ctx
                                     .db
                                     .entity()
                                     .entity_id()
-                                    .find(&ufo.entity_id)
+                                    .find(ufo.entity_id)
                                     .ok_or("UFO entity not found")?;
 
-                                // Add mass to ufo
-                                ufo_entity.mass += cow_entity.mass;
+                                // Another cow may have filled the hold on the way up
+                                if is_hold_full(ctx, ufo.entity_id, ufo_entity.mass) {
+                                    release_abducted_cows(ctx, arena_id, ufo.entity_id);
+                                    continue;
+                                }
+
+                                // Stow the cow in the hold
+                                store_cow(ctx, ufo.entity_id, arena_id, cow.kind, cow_entity.mass)?;
+                                record_abduction(ctx, ufo.player_id, cow.kind);
+                                emit_event(ctx, ufo.player_id, GameEvent::CowAbducted);
 
-                                // Update UFO and UFO entity
+                                // Update UFO
                                 ctx.db.ufo().entity_id().update(ufo);
-                                ctx.db.entity().entity_id().update(ufo_entity);
 
                                 // Delete cow and cow entity
                                 ctx.db.cow().delete(cow);
-                                ctx.db.entity().entity_id().delete(&cow_entity.entity_id);
+                                ctx.db.entity().entity_id().delete(cow_entity.entity_id);
 
                                 continue;
                             }
                             cow_entity.position = DbVector3 {
                                 x: ufo.position.x,
-                                y: cow_entity.position.y + 0.02,
+                                y: cow_entity.position.y + lift,
                                 z: ufo.position.z,
                             };
                             ctx.db.entity().entity_id().update(cow_entity);
@@ -347,3 +691,85 @@ fn process_abductions(ctx: &ReducerContext) -> Result<(), String> {
     }
     Ok(())
 }
+
+// Pushes overlapping UFOs apart and bounces them off each other, weighted by mass so big UFOs
+// barely budge. A UFO shoved by a heavier one drops whatever it was lifting or carrying.
+fn resolve_ufo_collisions(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let bounds = get_config(ctx, arena_id)?.bounds;
+    let mut bodies: Vec<(Ufo, Entity)> = ctx
+        .db
+        .ufo()
+        .arena_id()
+        .filter(arena_id)
+        .filter_map(|ufo| ctx.db.entity().entity_id().find(ufo.entity_id).map(|entity| (ufo, entity)))
+        .collect();
+    let mut moved = vec![false; bodies.len()];
+    let mut shoved = vec![false; bodies.len()];
+
+    for i in 0..bodies.len() {
+        for j in i + 1..bodies.len() {
+            let (left, right) = bodies.split_at_mut(j);
+            let (ufo_a, entity_a) = &mut left[i];
+            let (ufo_b, entity_b) = &mut right[0];
+            let pos_a = DbVector2::new(entity_a.position.x, entity_a.position.z);
+            let pos_b = DbVector2::new(entity_b.position.x, entity_b.position.z);
+            let offset = pos_b - pos_a;
+            let distance = offset.magnitude();
+            let overlap = mass_to_ufo_size(entity_a.mass) + mass_to_ufo_size(entity_b.mass) - distance;
+            if overlap <= 0.0 {
+                continue;
+            }
+            let normal = if distance > 0.0 { offset / distance } else { DbVector2::new(1.0, 0.0) };
+
+            // Each UFO moves by the share of the overlap the other one's mass accounts for
+            let mass_a = entity_a.mass as f32;
+            let mass_b = entity_b.mass as f32;
+            let share_a = mass_b / (mass_a + mass_b);
+            let pos_a = pos_a - normal * (overlap * share_a);
+            let pos_b = pos_b + normal * (overlap * (1.0 - share_a));
+            entity_a.position.x = pos_a.x;
+            entity_a.position.z = pos_a.y;
+            entity_b.position.x = pos_b.x;
+            entity_b.position.z = pos_b.y;
+
+            // Exchange momentum along the line between them if they're closing in
+            let relative_velocity = ufo_a.velocity - ufo_b.velocity;
+            let closing = DbVector2::new(relative_velocity.x, relative_velocity.z).dot(normal);
+            if closing > 0.0 {
+                let impulse = (1.0 + UFO_RESTITUTION) * closing / (1.0 / mass_a + 1.0 / mass_b);
+                let push = DbVector3::new(normal.x, 0.0, normal.y) * impulse;
+                ufo_a.velocity -= push / mass_a;
+                ufo_b.velocity += push / mass_b;
+            }
+
+            moved[i] = true;
+            moved[j] = true;
+            if entity_a.mass > entity_b.mass {
+                shoved[j] = true;
+            } else if entity_b.mass > entity_a.mass {
+                shoved[i] = true;
+            }
+        }
+    }
+
+    for (index, (mut ufo, mut ufo_entity)) in bodies.into_iter().enumerate() {
+        if !moved[index] {
+            continue;
+        }
+        let ground_pos = bounds.constrain_circle(
+            DbVector2::new(ufo_entity.position.x, ufo_entity.position.z),
+            mass_to_ufo_size(ufo_entity.mass),
+        );
+        ufo_entity.position.x = ground_pos.x;
+        ufo_entity.position.z = ground_pos.y;
+        ctx.db.entity().entity_id().update(ufo_entity);
+        if shoved[index] {
+            // Carrying cows is risky, a heavier UFO can knock them out of the hold
+            release_abducted_cows(ctx, arena_id, ufo.entity_id);
+            drop_cargo(ctx, ufo.entity_id)?;
+            ufo.abducted_entity = None;
+        }
+        ctx.db.ufo().entity_id().update(ufo);
+    }
+    Ok(())
+}
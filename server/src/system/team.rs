@@ -0,0 +1,103 @@
+use spacetimedb::{table, ReducerContext, Table};
+use crate::{
+    system::player::{player, Player},
+    system::system::get_config,
+    util::constants::TEAM_NAMES,
+};
+
+// Players on the same team share a score and can't abduct each other or each other's cows
+#[table(name = team, public)]
+#[derive(Debug, Clone)]
+pub struct Team {
+    #[primary_key]
+    #[auto_inc]
+    pub team_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub name: String,
+    // Sum of the points every member has scored this round
+    pub score: u32,
+}
+
+// Creates the arena's teams and spreads everyone already in it across them
+pub fn setup_teams(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    clear_teams(ctx, arena_id);
+    for name in TEAM_NAMES {
+        ctx.db.team().try_insert(Team {
+            team_id: 0,
+            arena_id,
+            name: name.to_string(),
+            score: 0,
+        })?;
+    }
+    for player in ctx.db.player().iter().filter(|player| player.arena_id == Some(arena_id)) {
+        assign_team(ctx, player.player_id)?;
+    }
+    Ok(())
+}
+
+// Deletes the arena's teams and takes everyone in it off their team
+pub fn clear_teams(ctx: &ReducerContext, arena_id: u32) {
+    ctx.db.team().arena_id().delete(arena_id);
+    for player in ctx.db.player().iter().filter(|player| player.arena_id == Some(arena_id)) {
+        if player.team_id.is_some() {
+            ctx.db.player().identity().update(Player { team_id: None, ..player });
+        }
+    }
+}
+
+// Puts the player on the smallest team in their arena, if it's playing with teams. Players
+// already on one of the arena's teams stay where they are.
+pub fn assign_team(ctx: &ReducerContext, player_id: u32) -> Result<(), String> {
+    let player = ctx.db.player().player_id().find(player_id).ok_or("Player not found")?;
+    let Some(arena_id) = player.arena_id else {
+        return Ok(());
+    };
+    if !get_config(ctx, arena_id)?.teams {
+        return Ok(());
+    }
+    let teams: Vec<Team> = ctx.db.team().arena_id().filter(arena_id).collect();
+    if player.team_id.is_some_and(|team_id| teams.iter().any(|team| team.team_id == team_id)) {
+        return Ok(());
+    }
+
+    let smallest = teams
+        .iter()
+        .min_by_key(|team| {
+            ctx.db
+                .player()
+                .iter()
+                .filter(|member| member.team_id == Some(team.team_id))
+                .count()
+        })
+        .ok_or("Arena has no teams")?;
+    log::info!("Player {} joined team {}", player_id, smallest.name);
+    ctx.db.player().identity().update(Player {
+        team_id: Some(smallest.team_id),
+        ..player
+    });
+    Ok(())
+}
+
+pub fn add_team_points(ctx: &ReducerContext, team_id: u32, points: u32) {
+    if let Some(mut team) = ctx.db.team().team_id().find(team_id) {
+        team.score += points;
+        ctx.db.team().team_id().update(team);
+    }
+}
+
+pub fn reset_team_scores(ctx: &ReducerContext, arena_id: u32) {
+    for team in ctx.db.team().arena_id().filter(arena_id) {
+        ctx.db.team().team_id().update(Team { score: 0, ..team });
+    }
+}
+
+pub fn team_of(ctx: &ReducerContext, player_id: u32) -> Option<u32> {
+    ctx.db.player().player_id().find(player_id).and_then(|player| player.team_id)
+}
+
+// Whether two players are teammates. Players without a team have no teammates.
+pub fn are_teammates(ctx: &ReducerContext, player_a: u32, player_b: u32) -> bool {
+    let team_a = team_of(ctx, player_a);
+    team_a.is_some() && team_a == team_of(ctx, player_b)
+}
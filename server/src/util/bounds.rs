@@ -0,0 +1,329 @@
+use serde::Deserialize;
+use spacetimedb::rand::Rng;
+use spacetimedb::SpacetimeType;
+use crate::util::math::DbVector2;
+
+const RANDOM_POINT_ATTEMPTS: u32 = 20;
+const CONSTRAIN_ITERATIONS: u32 = 4;
+
+#[derive(SpacetimeType, Debug, Clone, Copy)]
+pub struct BoundsRect {
+    pub min: DbVector2,
+    pub max: DbVector2,
+}
+
+// The playable area on the ground plane (x, z)
+#[derive(SpacetimeType, Debug, Clone)]
+pub enum WorldBounds {
+    Rect(BoundsRect),
+    // Vertices in order, either winding. The last vertex connects back to the first.
+    Polygon(Vec<DbVector2>),
+}
+
+// Bounds as written in map files
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundsDefinition {
+    Rect { width: f32, depth: f32 },
+    Polygon(Vec<[f32; 2]>),
+}
+
+impl BoundsDefinition {
+    pub fn to_world_bounds(&self) -> WorldBounds {
+        match self {
+            BoundsDefinition::Rect { width, depth } => WorldBounds::Rect(BoundsRect {
+                min: DbVector2::new(0.0, 0.0),
+                max: DbVector2::new(*width, *depth),
+            }),
+            BoundsDefinition::Polygon(points) => {
+                WorldBounds::Polygon(points.iter().map(|[x, z]| DbVector2::new(*x, *z)).collect())
+            }
+        }
+    }
+}
+
+impl WorldBounds {
+    pub fn square(size: f32) -> Self {
+        WorldBounds::Rect(BoundsRect {
+            min: DbVector2::new(0.0, 0.0),
+            max: DbVector2::new(size, size),
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        // Spawning picks random points across the bounding box, which needs a finite size
+        let bbox = self.bounding_box();
+        if !(bbox.max.x - bbox.min.x).is_finite() || !(bbox.max.y - bbox.min.y).is_finite() {
+            return Err("World bounds must be finite".to_string());
+        }
+        match self {
+            WorldBounds::Rect(rect) => {
+                if rect.max.x <= rect.min.x || rect.max.y <= rect.min.y {
+                    return Err("World bounds must have a positive width and depth".to_string());
+                }
+            }
+            WorldBounds::Polygon(points) => {
+                if points.len() < 3 {
+                    return Err("World bounds polygon needs at least 3 points".to_string());
+                }
+                if signed_area(points).abs() <= f32::EPSILON {
+                    return Err("World bounds polygon must have a positive area".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn bounding_box(&self) -> BoundsRect {
+        match self {
+            WorldBounds::Rect(rect) => *rect,
+            WorldBounds::Polygon(points) => {
+                let mut min = DbVector2::new(f32::MAX, f32::MAX);
+                let mut max = DbVector2::new(f32::MIN, f32::MIN);
+                for point in points {
+                    min = DbVector2::new(min.x.min(point.x), min.y.min(point.y));
+                    max = DbVector2::new(max.x.max(point.x), max.y.max(point.y));
+                }
+                BoundsRect { min, max }
+            }
+        }
+    }
+
+    pub fn contains(&self, point: DbVector2) -> bool {
+        match self {
+            WorldBounds::Rect(rect) => {
+                rect.min.x <= point.x && point.x <= rect.max.x && rect.min.y <= point.y && point.y <= rect.max.y
+            }
+            WorldBounds::Polygon(points) => {
+                // Even-odd ray cast along +x
+                let mut inside = false;
+                for (a, b) in edges(points) {
+                    if (a.y > point.y) != (b.y > point.y) {
+                        let cross_x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                        if point.x < cross_x {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            }
+        }
+    }
+
+    // Distance from a point to the nearest edge of the bounds
+    pub fn distance_to_edge(&self, point: DbVector2) -> f32 {
+        match self {
+            WorldBounds::Rect(rect) => (point.x - rect.min.x)
+                .abs()
+                .min((rect.max.x - point.x).abs())
+                .min((point.y - rect.min.y).abs())
+                .min((rect.max.y - point.y).abs()),
+            WorldBounds::Polygon(points) => edges(points)
+                .map(|(a, b)| (point - closest_point_on_segment(a, b, point)).magnitude())
+                .fold(f32::MAX, f32::min),
+        }
+    }
+
+    // Moves a circle so that all of it is inside the bounds. A circle too big to fit is centered
+    // on the axis it doesn't fit along.
+    pub fn constrain_circle(&self, point: DbVector2, radius: f32) -> DbVector2 {
+        match self {
+            WorldBounds::Rect(rect) => DbVector2::new(
+                constrain_axis(point.x, rect.min.x, rect.max.x, radius),
+                constrain_axis(point.y, rect.min.y, rect.max.y, radius),
+            ),
+            WorldBounds::Polygon(points) => {
+                let mut point = point;
+                for _ in 0..CONSTRAIN_ITERATIONS {
+                    // Bring the center back inside through the nearest edge first
+                    if !self.contains(point) {
+                        let (closest, inward) = nearest_edge(points, point);
+                        point = closest + inward * radius;
+                        continue;
+                    }
+                    let mut moved = false;
+                    for (a, b) in edges(points) {
+                        let closest = closest_point_on_segment(a, b, point);
+                        let offset = point - closest;
+                        let distance = offset.magnitude();
+                        if distance < radius {
+                            let normal = if distance > 0.0 { offset / distance } else { inward_normal(points, a, b) };
+                            point = closest + normal * radius;
+                            moved = true;
+                        }
+                    }
+                    if !moved {
+                        break;
+                    }
+                }
+                point
+            }
+        }
+    }
+
+    // A random point at least `margin` away from every edge
+    pub fn random_point<R: Rng>(&self, rng: &mut R, margin: f32) -> Option<DbVector2> {
+        let bbox = self.bounding_box();
+        for _ in 0..RANDOM_POINT_ATTEMPTS {
+            let candidate = DbVector2::new(
+                random_in_axis(rng, bbox.min.x, bbox.max.x, margin),
+                random_in_axis(rng, bbox.min.y, bbox.max.y, margin),
+            );
+            if self.contains(candidate) && self.distance_to_edge(candidate) >= margin {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
+
+fn constrain_axis(value: f32, min: f32, max: f32, radius: f32) -> f32 {
+    if max - min <= radius * 2.0 {
+        (min + max) / 2.0
+    } else {
+        value.clamp(min + radius, max - radius)
+    }
+}
+
+fn random_in_axis<R: Rng>(rng: &mut R, min: f32, max: f32, margin: f32) -> f32 {
+    if max - min <= margin * 2.0 {
+        (min + max) / 2.0
+    } else {
+        rng.gen_range(min + margin..max - margin)
+    }
+}
+
+fn edges(points: &[DbVector2]) -> impl Iterator<Item = (DbVector2, DbVector2)> + '_ {
+    points.iter().enumerate().map(|(i, a)| (*a, points[(i + 1) % points.len()]))
+}
+
+fn inward_normal(points: &[DbVector2], a: DbVector2, b: DbVector2) -> DbVector2 {
+    let edge = b - a;
+    if signed_area(points) < 0.0 {
+        DbVector2::new(edge.y, -edge.x).normalized()
+    } else {
+        DbVector2::new(-edge.y, edge.x).normalized()
+    }
+}
+
+// Closest point on the polygon's outline, and the inward normal of the edge it's on
+fn nearest_edge(points: &[DbVector2], point: DbVector2) -> (DbVector2, DbVector2) {
+    edges(points)
+        .map(|(a, b)| (closest_point_on_segment(a, b, point), inward_normal(points, a, b)))
+        .min_by(|(x, _), (y, _)| (point - *x).sqr_magnitude().total_cmp(&(point - *y).sqr_magnitude()))
+        .unwrap_or((point, DbVector2::new(0.0, 0.0)))
+}
+
+fn signed_area(points: &[DbVector2]) -> f32 {
+    edges(points).map(|(a, b)| a.x * b.y - b.x * a.y).sum::<f32>() / 2.0
+}
+
+pub fn closest_point_on_segment(a: DbVector2, b: DbVector2, point: DbVector2) -> DbVector2 {
+    let edge = b - a;
+    let length = edge.sqr_magnitude();
+    if length == 0.0 {
+        return a;
+    }
+    let t = ((point - a).dot(edge) / length).clamp(0.0, 1.0);
+    a + edge * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[[f32; 2]]) -> Vec<DbVector2> {
+        points.iter().map(|[x, z]| DbVector2::new(*x, *z)).collect()
+    }
+
+    fn square(clockwise: bool) -> Vec<DbVector2> {
+        let mut points = polygon(&[[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]]);
+        if clockwise {
+            points.reverse();
+        }
+        points
+    }
+
+    // An L with the top right quarter cut out
+    fn l_shape() -> WorldBounds {
+        WorldBounds::Polygon(polygon(&[[0.0, 0.0], [10.0, 0.0], [10.0, 5.0], [5.0, 5.0], [5.0, 10.0], [0.0, 10.0]]))
+    }
+
+    fn assert_near(actual: DbVector2, x: f32, z: f32) {
+        assert!(
+            (actual.x - x).abs() < 1e-4 && (actual.y - z).abs() < 1e-4,
+            "expected ({}, {}), got {:?}",
+            x,
+            z,
+            actual
+        );
+    }
+
+    #[test]
+    fn polygon_contains_points_inside_only() {
+        let bounds = l_shape();
+        assert!(bounds.contains(DbVector2::new(2.0, 2.0)));
+        assert!(bounds.contains(DbVector2::new(8.0, 2.0)));
+        assert!(bounds.contains(DbVector2::new(2.0, 8.0)));
+        // The cut out corner and anything past the edges
+        assert!(!bounds.contains(DbVector2::new(8.0, 8.0)));
+        assert!(!bounds.contains(DbVector2::new(-1.0, 2.0)));
+        assert!(!bounds.contains(DbVector2::new(2.0, 11.0)));
+    }
+
+    #[test]
+    fn inward_normal_points_inside_for_either_winding() {
+        for clockwise in [false, true] {
+            let points = square(clockwise);
+            for (a, b) in edges(&points) {
+                let middle = (a + b) / 2.0;
+                let normal = inward_normal(&points, a, b);
+                assert!((normal.magnitude() - 1.0).abs() < 1e-4);
+                assert!(WorldBounds::Polygon(points.clone()).contains(middle + normal * 0.1));
+            }
+        }
+    }
+
+    #[test]
+    fn constrain_circle_keeps_the_circle_inside_a_polygon() {
+        for clockwise in [false, true] {
+            let bounds = WorldBounds::Polygon(square(clockwise));
+            // Already inside with room to spare
+            assert_near(bounds.constrain_circle(DbVector2::new(5.0, 5.0), 1.0), 5.0, 5.0);
+            // Overlapping an edge
+            assert_near(bounds.constrain_circle(DbVector2::new(0.5, 5.0), 1.0), 1.0, 5.0);
+            // Center outside the polygon
+            assert_near(bounds.constrain_circle(DbVector2::new(12.0, 5.0), 1.0), 9.0, 5.0);
+            // Pushed out of a corner along both edges
+            assert_near(bounds.constrain_circle(DbVector2::new(0.2, 0.2), 1.0), 1.0, 1.0);
+        }
+    }
+
+    #[test]
+    fn constrain_circle_clears_a_concave_corner() {
+        let bounds = l_shape();
+        for point in [DbVector2::new(8.0, 8.0), DbVector2::new(5.2, 5.2), DbVector2::new(4.8, 5.5)] {
+            let constrained = bounds.constrain_circle(point, 0.5);
+            assert!(bounds.contains(constrained), "{:?} ended up at {:?}", point, constrained);
+            assert!(bounds.distance_to_edge(constrained) >= 0.5 - 1e-4, "{:?} ended up at {:?}", point, constrained);
+        }
+    }
+
+    #[test]
+    fn constrain_circle_in_a_rect() {
+        let bounds = WorldBounds::square(10.0);
+        assert_near(bounds.constrain_circle(DbVector2::new(-3.0, 12.0), 1.0), 1.0, 9.0);
+        // Too big to fit, so it's centered
+        assert_near(bounds.constrain_circle(DbVector2::new(2.0, 2.0), 6.0), 5.0, 5.0);
+    }
+
+    #[test]
+    fn validate_rejects_degenerate_bounds() {
+        assert!(WorldBounds::square(10.0).validate().is_ok());
+        assert!(WorldBounds::Polygon(square(true)).validate().is_ok());
+        assert!(WorldBounds::square(0.0).validate().is_err());
+        assert!(WorldBounds::square(f32::INFINITY).validate().is_err());
+        assert!(WorldBounds::Polygon(polygon(&[[0.0, 0.0], [5.0, 5.0], [10.0, 10.0]])).validate().is_err());
+        assert!(WorldBounds::Polygon(polygon(&[[0.0, 0.0], [10.0, 0.0]])).validate().is_err());
+    }
+}
@@ -3,5 +3,79 @@ pub const START_PLAYER_MASS: u32 = 2;
 pub const START_PLAYER_HEIGHT: f32 = 0f32;
 pub const COW_MASS_MIN: u32 = 2;
 pub const COW_MASS_MAX: u32 = 4;
-pub const TARGET_COW_COUNT: usize = 10;
+pub const COWS_PER_UFO: usize = 5;
+pub const MIN_COW_COUNT: usize = 5;
+pub const MAX_COW_COUNT: usize = 50;
+pub const MAX_COWS_SPAWNED_PER_TICK: usize = 2;
+pub const COW_RESPAWN_DELAY_MS: u64 = 3000;
+pub const COW_SPAWN_BEAM_CLEARANCE: f32 = 1.0;
+pub const COW_SPAWN_ATTEMPTS: u32 = 10;
 pub const WORLD_SIZE: u64 = 10;
+// The client's UfoController draws UFOs at this radius too
+pub const UFO_RADIUS_PER_MASS: f32 = 0.01;
+pub const UFO_SPAWN_HEIGHT: f32 = 1.0;
+pub const OBSTACLE_SHADOW_MARGIN: f32 = 0.5;
+pub const GENERATED_SPAWN_POINTS: usize = 4;
+pub const GENERATED_DROP_OFF_ZONES: usize = 2;
+pub const GENERATED_FEATURE_SPACING: f32 = 0.5;
+pub const GENERATED_PATH_CLEARANCE: f32 = 0.25;
+pub const GENERATED_GRID_CELL_SIZE: f32 = 0.25;
+pub const GENERATED_PLACEMENT_ATTEMPTS: u32 = 50;
+pub const GENERATED_MAX_SIZE: f32 = 100.0;
+pub const START_SKILL_RATING: u32 = 1000;
+pub const MATCHMAKING_INTERVAL_MS: u64 = 2000;
+pub const MATCHMAKING_SKILL_WINDOW: f32 = 100.0;
+pub const MATCHMAKING_SKILL_WINDOW_GROWTH_PER_SEC: f32 = 25.0;
+pub const MATCHMAKING_MIN_GROUP_SIZE: usize = 2;
+pub const MATCHMAKING_MAX_WAIT_MS: u64 = 30_000;
+pub const EMPTY_ARENA_TIMEOUT_MS: u64 = 60_000;
+pub const MIN_PLAYERS_TO_START_ROUND: usize = 2;
+pub const ROUND_TIMER_INTERVAL_MS: u64 = 1000;
+pub const ROUND_COUNTDOWN_MS: u64 = 10_000;
+pub const ROUND_DURATION_MS: u64 = 180_000;
+pub const ROUND_RESULTS_MS: u64 = 10_000;
+pub const SKILL_RATING_PER_RANK: u32 = 10;
+pub const COW_POINTS_PER_MASS: u32 = 10;
+pub const GOLDEN_COW_CHANCE: f64 = 0.05;
+pub const GOLDEN_COW_POINTS_MULTIPLIER: u32 = 5;
+pub const LEADERBOARD_SIZE: usize = 10;
+pub const LEADERBOARD_INTERVAL_MS: u64 = 1000;
+pub const PROCESS_GAME_INTERVAL_MS: u64 = 50;
+pub const MAX_BEAM_ENERGY: f32 = 100.0;
+pub const BEAM_DRAIN_PER_SEC: f32 = 10.0;
+pub const BEAM_DRAIN_PER_LIFTED_MASS_PER_SEC: f32 = 2.0;
+pub const BEAM_RECHARGE_PER_SEC: f32 = 15.0;
+pub const MIN_BEAM_ENERGY_TO_START: f32 = 10.0;
+pub const MASS_DECAY_THRESHOLD: u32 = 20;
+pub const MASS_DECAY_PER_SEC: f32 = 0.02;
+pub const UFO_ACCELERATION: f32 = 12.0;
+pub const UFO_DRAG_PER_SEC: f32 = 4.0;
+pub const BOOST_ENERGY_COST: f32 = 25.0;
+pub const BOOST_COOLDOWN_MS: u64 = 3000;
+pub const BOOST_DURATION_MS: u64 = 500;
+pub const BOOST_SPEED_MULTIPLIER: f32 = 2.5;
+pub const MIN_UFO_ALTITUDE: f32 = 0.5;
+pub const MAX_UFO_ALTITUDE: f32 = 3.0;
+pub const UFO_CLIMB_PER_SEC: f32 = 1.0;
+pub const BEAM_RADIUS_PER_ALTITUDE: f32 = 0.5;
+pub const UFO_SPEED_LOSS_PER_ALTITUDE: f32 = 0.5;
+pub const COW_LIFT_PER_SEC: f32 = 0.4;
+pub const UFO_RESTITUTION: f32 = 0.5;
+pub const COW_SEPARATION_STRENGTH: f32 = 0.5;
+pub const COW_CONTEST_DRIFT_PER_SEC: f32 = 1.0;
+pub const CARGO_BASE_CAPACITY: u32 = 1;
+pub const CARGO_MASS_PER_SLOT: u32 = 5;
+pub const TEAM_NAMES: [&str; 2] = ["Red", "Blue"];
+pub const SAFE_ZONE_INTERVAL_MS: u64 = 250;
+pub const SAFE_ZONE_HOLD_MS: u64 = 30_000;
+pub const SAFE_ZONE_SHRINK_PER_SEC: f32 = 0.1;
+pub const SAFE_ZONE_SHRINK_FACTOR: f32 = 0.6;
+pub const SAFE_ZONE_MIN_RADIUS: f32 = 1.0;
+pub const SAFE_ZONE_MASS_LOSS_PER_SEC: f32 = 2.0;
+pub const CONTROL_ZONE_RADIUS: f32 = 1.0;
+pub const CONTROL_ZONE_RELOCATE_MS: u64 = 30_000;
+pub const CONTROL_ZONE_AWARD_MS: u64 = 1000;
+pub const CONTROL_ZONE_POINTS: u32 = 5;
+pub const BOT_THINK_INTERVAL_MS: u64 = 100;
+pub const BOT_FILL_COUNT: u32 = 4;
+pub const BOT_WANDER_TURN_CHANCE: f64 = 0.05;
@@ -124,6 +124,10 @@ impl DbVector2 {
     pub fn normalized(self) -> DbVector2 {
         self / self.magnitude()
     }
+
+    pub fn dot(&self, other: DbVector2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
 }
 
 impl std::ops::Add<&DbVector3> for DbVector3 {
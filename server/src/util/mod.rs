@@ -1,3 +1,5 @@
+#[allow(clippy::module_inception)]
 pub mod util;
 pub mod math;
-pub mod constants;
\ No newline at end of file
+pub mod constants;
+pub mod bounds;
\ No newline at end of file
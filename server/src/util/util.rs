@@ -1,18 +1,37 @@
-use crate::util::constants::{START_PLAYER_MASS, START_PLAYER_SPEED};
+use crate::util::constants::{
+    BEAM_RADIUS_PER_ALTITUDE, PROCESS_GAME_INTERVAL_MS, START_PLAYER_MASS, START_PLAYER_SPEED,
+    UFO_SPAWN_HEIGHT, UFO_SPEED_LOSS_PER_ALTITUDE,
+};
 use crate::util::math::DbVector2;
 
 pub fn mass_to_max_move_speed(mass: u32) -> f32 {
     2.0 * START_PLAYER_SPEED as f32 / (1.0 + (mass as f32 / START_PLAYER_MASS as f32).sqrt())
 }
 
-pub fn is_cow_in_beam(cow_pos: DbVector2, ufo_pos: DbVector2) -> bool {
-    let x_low = ufo_pos.x * 0.9;
-    let x_high = ufo_pos.x * 1.1;
-    let z_low = ufo_pos.y * 0.9;
-    let z_high = ufo_pos.y * 1.1;
+// Top UFO speed in units per second. The same as when input moved the UFO directly, once per
+// 60th of a second.
+pub fn mass_to_max_ufo_velocity(mass: u32) -> f32 {
+    mass_to_max_move_speed(mass) / 60.0 * 1000.0 / PROCESS_GAME_INTERVAL_MS as f32
+}
+
+// The beam spreads out the higher the UFO flies
+pub fn altitude_to_beam_radius(altitude: f32) -> f32 {
+    altitude * BEAM_RADIUS_PER_ALTITUDE
+}
 
-    let is_close_x = x_low <= cow_pos.x && cow_pos.x <= x_high;
-    let is_close_z = z_low <= cow_pos.y && cow_pos.y <= z_high;
+// Thin air up high makes UFOs slower to accelerate and slower overall. UFOs at spawn height
+// fly at their normal speed.
+pub fn altitude_to_speed_factor(altitude: f32) -> f32 {
+    let drag = |height: f32| 1.0 + height.max(0.0) * UFO_SPEED_LOSS_PER_ALTITUDE;
+    drag(UFO_SPAWN_HEIGHT) / drag(altitude)
+}
+
+// How hard a beam pulls on a cow. Heavier UFOs pull harder, and the pull weakens away from the
+// center of the beam.
+pub fn beam_pull(ufo_mass: u32, distance: f32) -> f32 {
+    ufo_mass as f32 / (1.0 + distance)
+}
 
-    is_close_x && is_close_z
+pub fn is_cow_in_beam(cow_pos: DbVector2, ufo_pos: DbVector2, beam_radius: f32) -> bool {
+    (cow_pos - ufo_pos).magnitude() <= beam_radius
 }
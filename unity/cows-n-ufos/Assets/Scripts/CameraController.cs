@@ -4,7 +4,7 @@ using UnityEngine;
 
 public class CameraController : MonoBehaviour
 {
-    public static float WorldSize = 0.0f;
+    public static Vector2 ArenaCenter = Vector2.zero;
 
     [Header("Orbit Camera Settings")]
     public float distance = 5.0f;         // Distance from target
@@ -32,7 +32,7 @@ public class CameraController : MonoBehaviour
     private void LateUpdate()
     {
         // Calculate the target position
-        Vector3 arenaCenterTransform = new Vector3(WorldSize / 2, WorldSize / 2, -10.0f);
+        Vector3 arenaCenterTransform = new Vector3(ArenaCenter.x, ArenaCenter.y, -10.0f);
         if (PlayerController.Instance == null || !GameManager.IsConnected())
         {
             // Set the camera to be in middle of the arena if we are not connected or 
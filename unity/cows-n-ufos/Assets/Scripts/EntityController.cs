@@ -56,12 +56,15 @@ public abstract class EntityController : MonoBehaviour
 		transform.localScale = Vector3.Lerp(transform.localScale, TargetScale, LerpTime);
 	}
 
-	public static Vector3 MassToScale(uint mass)
+	// Models are one unit across, so this draws the entity at the size the server uses for walls
+	// and collisions
+	protected virtual Vector3 MassToScale(uint mass)
 	{
-		var diameter = mass * 1.01f;
+		var diameter = MassToDiameter(mass);
 		return new Vector3(diameter, diameter, diameter);
 	}
 
+	// Same as mass_to_cow_size on the server
 	public static float MassToRadius(uint mass) => Mathf.Sqrt(mass);
 	public static float MassToDiameter(uint mass) => MassToRadius(mass) * 2;
 }
\ No newline at end of file
@@ -27,6 +27,10 @@ public class GameManager : MonoBehaviour
     public static Dictionary<uint, EntityController> Entities = new Dictionary<uint, EntityController>();
     public static Dictionary<uint, PlayerController> Players = new Dictionary<uint, PlayerController>();
 
+    // The arena the local player is in. Only its UFOs and cows are drawn.
+    public static uint? ArenaId { get; private set; }
+    private readonly List<GameObject> borders = new List<GameObject>();
+
     private void Start()
     {
         Instance = this;
@@ -53,27 +57,104 @@ public class GameManager : MonoBehaviour
         Conn = builder.Build();
     }
 
-    private void SetupArena(float worldSize)
+    private void SetupArena(WorldBounds bounds)
     {
-        CreateBorderCube(new Vector2(worldSize / 2.0f, worldSize + borderThickness / 2),
-            new Vector2(worldSize + borderThickness * 2.0f, borderThickness)); //North
-        CreateBorderCube(new Vector2(worldSize / 2.0f, -borderThickness / 2),
-            new Vector2(worldSize + borderThickness * 2.0f, borderThickness)); //South
-        CreateBorderCube(new Vector2(worldSize + borderThickness / 2, worldSize / 2.0f),
-            new Vector2(borderThickness, worldSize + borderThickness * 2.0f)); //East
-        CreateBorderCube(new Vector2(-borderThickness / 2, worldSize / 2.0f),
-            new Vector2(borderThickness, worldSize + borderThickness * 2.0f)); //West
+        ClearBorders();
+
+        var corners = BoundsCorners(bounds);
+        if (corners.Count < 3) return;
+
+        // Push each wall outwards, whichever way the corners wind
+        var area = 0.0f;
+        for (var i = 0; i < corners.Count; i++)
+        {
+            var a = corners[i];
+            var b = corners[(i + 1) % corners.Count];
+            area += a.x * b.y - b.x * a.y;
+        }
+        var outwardSign = area > 0 ? 1.0f : -1.0f;
+
+        var min = corners[0];
+        var max = corners[0];
+        for (var i = 0; i < corners.Count; i++)
+        {
+            var a = corners[i];
+            var b = corners[(i + 1) % corners.Count];
+            var edge = b - a;
+            var outward = new Vector2(edge.y, -edge.x).normalized * outwardSign;
+            CreateBorderCube((a + b) / 2 + outward * borderThickness / 2, edge, borderThickness);
+            min = Vector2.Min(min, a);
+            max = Vector2.Max(max, a);
+        }
 
-        CameraController.WorldSize = worldSize;
+        CameraController.ArenaCenter = (min + max) / 2;
     }
 
-    private void CreateBorderCube(Vector2 position, Vector2 scale)
+    private void ClearBorders()
+    {
+        foreach (var border in borders)
+        {
+            Destroy(border);
+        }
+        borders.Clear();
+    }
+
+    private static List<Vector2> BoundsCorners(WorldBounds bounds)
+    {
+        switch (bounds)
+        {
+            case WorldBounds.Rect(var rect):
+                return new List<Vector2>
+                {
+                    new Vector2(rect.Min.X, rect.Min.Y),
+                    new Vector2(rect.Max.X, rect.Min.Y),
+                    new Vector2(rect.Max.X, rect.Max.Y),
+                    new Vector2(rect.Min.X, rect.Max.Y),
+                };
+            case WorldBounds.Polygon(var points):
+                return points.ConvertAll(point => (Vector2)point);
+            default:
+                return new List<Vector2>();
+        }
+    }
+
+    private void CreateBorderCube(Vector2 position, Vector2 edge, float thickness)
     {
         var cube = GameObject.CreatePrimitive(PrimitiveType.Cube);
         cube.name = "Border";
-        cube.transform.localScale = new Vector3(scale.x, 1, scale.y);
-        cube.transform.position = new Vector3(position.x, 0.5f, position.y);
+        cube.transform.localScale = new Vector3(thickness, 1, edge.magnitude + thickness);
+        cube.transform.SetPositionAndRotation(new Vector3(position.x, 0.5f, position.y),
+            Quaternion.LookRotation(new Vector3(edge.x, 0, edge.y)));
         cube.GetComponent<MeshRenderer>().material = borderMaterial;
+        borders.Add(cube);
+    }
+
+    // Drops everything from the old arena and draws the one the local player is now in
+    private void EnterArena(EventContext context, uint? arenaId)
+    {
+        ArenaId = arenaId;
+        foreach (var entityController in new List<EntityController>(Entities.Values))
+        {
+            entityController.OnDelete(context);
+        }
+        Entities.Clear();
+        ClearBorders();
+
+        if (arenaId is not uint id) return;
+
+        var config = Conn.Db.Config.ArenaId.Find(id);
+        if (config != null)
+        {
+            SetupArena(config.Bounds);
+        }
+        foreach (var ufo in Conn.Db.Ufo.ArenaId.Filter(id))
+        {
+            SpawnUfo(ufo);
+        }
+        foreach (var cow in Conn.Db.Cow.ArenaId.Filter(id))
+        {
+            SpawnCow(cow);
+        }
     }
 
     // Called when we connect to SpacetimeDB and receive our client identity
@@ -90,7 +171,10 @@ public class GameManager : MonoBehaviour
         conn.Db.Cow.OnInsert += CowOnInsert;
         conn.Db.Cow.OnUpdate += CowOnUpdate;
         conn.Db.Player.OnInsert += PlayerOnInsert;
+        conn.Db.Player.OnUpdate += PlayerOnUpdate;
         conn.Db.Player.OnDelete += PlayerOnDelete;
+        conn.Db.Config.OnInsert += ConfigOnInsert;
+        conn.Db.Config.OnUpdate += ConfigOnUpdate;
         DB = conn;
 
         OnConnected?.Invoke();
@@ -103,9 +187,16 @@ public class GameManager : MonoBehaviour
 
     private static void UfoOnInsert(EventContext context, Ufo insertedValue)
     {
-        var player = GetOrCreatePlayer(insertedValue.PlayerId);
-        var entityController = PrefabManager.SpawnUfo(insertedValue, player);
-        Entities.Add(insertedValue.EntityId, entityController);
+        SpawnUfo(insertedValue);
+    }
+
+    private static void SpawnUfo(Ufo ufo)
+    {
+        if (ufo.ArenaId != ArenaId || Entities.ContainsKey(ufo.EntityId)) return;
+
+        var player = GetOrCreatePlayer(ufo.PlayerId);
+        var entityController = PrefabManager.SpawnUfo(ufo, player);
+        Entities.Add(ufo.EntityId, entityController);
     }
 
     private static void UfoOnUpdate(EventContext context, Ufo oldUfo, Ufo newUfo)
@@ -136,10 +227,17 @@ public class GameManager : MonoBehaviour
 
     private static void CowOnInsert(EventContext context, Cow insertedValue)
     {
-        var entityController = PrefabManager.SpawnCow(insertedValue);
+        SpawnCow(insertedValue);
+    }
+
+    private static void SpawnCow(Cow cow)
+    {
+        if (cow.ArenaId != ArenaId || Entities.ContainsKey(cow.EntityId)) return;
+
+        var entityController = PrefabManager.SpawnCow(cow);
         Debug.Log("ADDING COW: " + entityController.name);
-        Entities.Add(insertedValue.EntityId, entityController);
-        Debug.Log("ID: " + Entities[insertedValue.EntityId].EntityId);
+        Entities.Add(cow.EntityId, entityController);
+        Debug.Log("ID: " + Entities[cow.EntityId].EntityId);
     }
 
     private static void CowOnUpdate(EventContext context, Cow oldCow, Cow newCow)
@@ -153,6 +251,35 @@ public class GameManager : MonoBehaviour
     private static void PlayerOnInsert(EventContext context, Player insertedPlayer)
     {
         GetOrCreatePlayer(insertedPlayer.PlayerId);
+        if (insertedPlayer.Identity == LocalIdentity && insertedPlayer.ArenaId != ArenaId)
+        {
+            Instance.EnterArena(context, insertedPlayer.ArenaId);
+        }
+    }
+
+    private static void PlayerOnUpdate(EventContext context, Player oldPlayer, Player newPlayer)
+    {
+        if (newPlayer.Identity == LocalIdentity && newPlayer.ArenaId != ArenaId)
+        {
+            Instance.EnterArena(context, newPlayer.ArenaId);
+        }
+    }
+
+    private static void ConfigOnInsert(EventContext context, Config insertedConfig)
+    {
+        if (insertedConfig.ArenaId == ArenaId)
+        {
+            Instance.SetupArena(insertedConfig.Bounds);
+        }
+    }
+
+    // A new map can change the arena's shape mid-game
+    private static void ConfigOnUpdate(EventContext context, Config oldConfig, Config newConfig)
+    {
+        if (newConfig.ArenaId == ArenaId)
+        {
+            Instance.SetupArena(newConfig.Bounds);
+        }
     }
 
     private static void PlayerOnDelete(EventContext context, Player deletedvalue)
@@ -194,9 +321,7 @@ public class GameManager : MonoBehaviour
         Debug.Log("Subscription applied!");
         OnSubscriptionApplied?.Invoke();
 
-        var worldSize = Conn.Db.Config.Id.Find(0).WorldSize;
-        SetupArena(worldSize);
-
+        // The arena is drawn once the matchmaker puts the local player in one
         ctx.Reducers.EnterGame(PlayerPrefs.GetString("PlayerName") ?? "Dingus");
     }
 
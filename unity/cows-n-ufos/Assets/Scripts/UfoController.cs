@@ -28,6 +28,9 @@ public class UfoController : EntityController
 		(Color)new Color32(2, 28, 146, 255),
 	};
 
+	// Same as UFO_RADIUS_PER_MASS on the server
+	const float UFO_RADIUS_PER_MASS = 0.01f;
+
 	private PlayerController Owner;
 
 	[SerializeField]
@@ -44,6 +47,12 @@ public class UfoController : EntityController
 		abductAction = InputSystem.actions.FindAction("Abduct");
 	}
 
+	protected override Vector3 MassToScale(uint mass)
+	{
+		var diameter = mass * UFO_RADIUS_PER_MASS * 2;
+		return new Vector3(diameter, diameter, diameter);
+	}
+
 	public override void OnDelete(EventContext context)
 	{
 		Debug.Log("UfoController: OnDelete");
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -17,7 +19,18 @@ namespace SpacetimeDB.Types
 
         public bool InvokeConnect(ReducerEventContext ctx, Reducer.Connect args)
         {
-            if (OnConnect == null) return false;
+            if (OnConnect == null)
+            {
+                if (InternalOnUnhandledReducerError != null)
+                {
+                    switch(ctx.Event.Status)
+                    {
+                        case Status.Failed(var reason): InternalOnUnhandledReducerError(ctx, new Exception(reason)); break;
+                        case Status.OutOfEnergy(var _): InternalOnUnhandledReducerError(ctx, new Exception("out of energy")); break;
+                    }
+                }
+                return false;
+            }
             OnConnect(
                 ctx
             );
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -22,7 +24,18 @@ namespace SpacetimeDB.Types
 
         public bool InvokeEnterGame(ReducerEventContext ctx, Reducer.EnterGame args)
         {
-            if (OnEnterGame == null) return false;
+            if (OnEnterGame == null)
+            {
+                if (InternalOnUnhandledReducerError != null)
+                {
+                    switch(ctx.Event.Status)
+                    {
+                        case Status.Failed(var reason): InternalOnUnhandledReducerError(ctx, new Exception(reason)); break;
+                        case Status.OutOfEnergy(var _): InternalOnUnhandledReducerError(ctx, new Exception("out of energy")); break;
+                    }
+                }
+                return false;
+            }
             OnEnterGame(
                 ctx,
                 args.Name
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -22,7 +24,18 @@ namespace SpacetimeDB.Types
 
         public bool InvokeSetName(ReducerEventContext ctx, Reducer.SetName args)
         {
-            if (OnSetName == null) return false;
+            if (OnSetName == null)
+            {
+                if (InternalOnUnhandledReducerError != null)
+                {
+                    switch(ctx.Event.Status)
+                    {
+                        case Status.Failed(var reason): InternalOnUnhandledReducerError(ctx, new Exception(reason)); break;
+                        case Status.OutOfEnergy(var _): InternalOnUnhandledReducerError(ctx, new Exception("out of energy")); break;
+                    }
+                }
+                return false;
+            }
             OnSetName(
                 ctx,
                 args.Name
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -14,29 +16,58 @@ namespace SpacetimeDB.Types
     {
         internal RemoteReducers(DbConnection conn, SetReducerFlags flags) : base(conn) => SetCallReducerFlags = flags;
         internal readonly SetReducerFlags SetCallReducerFlags;
+        internal event Action<ReducerEventContext, Exception>? InternalOnUnhandledReducerError;
     }
 
     public sealed partial class RemoteTables : RemoteTablesBase
     {
         public RemoteTables(DbConnection conn)
         {
+            AddTable(Achievement = new(conn));
+            AddTable(Admin = new(conn));
+            AddTable(Arena = new(conn));
+            AddTable(Bot = new(conn));
+            AddTable(BotTimer = new(conn));
+            AddTable(Cargo = new(conn));
             AddTable(ChangeCowDirectionTimer = new(conn));
             AddTable(Config = new(conn));
+            AddTable(ControlZone = new(conn));
+            AddTable(ControlZoneTimer = new(conn));
             AddTable(Cow = new(conn));
+            AddTable(CowRespawn = new(conn));
+            AddTable(DropOffZone = new(conn));
             AddTable(Entity = new(conn));
+            AddTable(Leaderboard = new(conn));
+            AddTable(LeaderboardTimer = new(conn));
             AddTable(LoggedOutPlayer = new(conn));
+            AddTable(MatchmakingQueue = new(conn));
+            AddTable(MatchmakingTimer = new(conn));
             AddTable(Message = new(conn));
             AddTable(MoveAllCowsTimer = new(conn));
+            AddTable(Obstacle = new(conn));
+            AddTable(Pasture = new(conn));
             AddTable(Player = new(conn));
+            AddTable(PlayerAchievement = new(conn));
+            AddTable(PlayerStats = new(conn));
             AddTable(ProcessGameTimer = new(conn));
+            AddTable(Round = new(conn));
+            AddTable(RoundResult = new(conn));
+            AddTable(RoundTimer = new(conn));
+            AddTable(SafeZone = new(conn));
+            AddTable(SafeZoneTimer = new(conn));
+            AddTable(Score = new(conn));
             AddTable(SpawnCowsTimer = new(conn));
+            AddTable(SpawnPoint = new(conn));
+            AddTable(Team = new(conn));
             AddTable(Ufo = new(conn));
         }
     }
 
     public sealed partial class SetReducerFlags { }
 
-        public interface IRemoteDbContext : IDbContext<RemoteTables, RemoteReducers, SetReducerFlags, SubscriptionBuilder> { }
+        public interface IRemoteDbContext : IDbContext<RemoteTables, RemoteReducers, SetReducerFlags, SubscriptionBuilder> {
+            public event Action<ReducerEventContext, Exception>? OnUnhandledReducerError;
+        }
 
         public sealed class EventContext : IEventContext, IRemoteDbContext
         {
@@ -97,6 +128,13 @@ namespace SpacetimeDB.Types
             /// Get this connection's <c>ConnectionId</c>.
             /// </summary>
             public ConnectionId ConnectionId => conn.ConnectionId;
+            /// <summary>
+            /// Register a callback to be called when a reducer with no handler returns an error.
+            /// </summary>
+            public event Action<ReducerEventContext, Exception>? OnUnhandledReducerError {
+                add => Reducers.InternalOnUnhandledReducerError += value;
+                remove => Reducers.InternalOnUnhandledReducerError -= value;
+            }
 
             internal EventContext(DbConnection conn, Event<Reducer> Event)
             {
@@ -163,6 +201,13 @@ namespace SpacetimeDB.Types
             /// Get this connection's <c>ConnectionId</c>.
             /// </summary>
             public ConnectionId ConnectionId => conn.ConnectionId;
+            /// <summary>
+            /// Register a callback to be called when a reducer with no handler returns an error.
+            /// </summary>
+            public event Action<ReducerEventContext, Exception>? OnUnhandledReducerError {
+                add => Reducers.InternalOnUnhandledReducerError += value;
+                remove => Reducers.InternalOnUnhandledReducerError -= value;
+            }
 
             internal ReducerEventContext(DbConnection conn, ReducerEvent<Reducer> reducerEvent)
             {
@@ -183,7 +228,7 @@ namespace SpacetimeDB.Types
                     return Event;
                 }
             }
-            
+
             /// <summary>
             /// Access to tables in the client cache, which stores a read-only replica of the remote database state.
             ///
@@ -234,6 +279,13 @@ namespace SpacetimeDB.Types
             /// Get this connection's <c>ConnectionId</c>.
             /// </summary>
             public ConnectionId ConnectionId => conn.ConnectionId;
+            /// <summary>
+            /// Register a callback to be called when a reducer with no handler returns an error.
+            /// </summary>
+            public event Action<ReducerEventContext, Exception>? OnUnhandledReducerError {
+                add => Reducers.InternalOnUnhandledReducerError += value;
+                remove => Reducers.InternalOnUnhandledReducerError -= value;
+            }
 
             internal ErrorContext(DbConnection conn, Exception error)
             {
@@ -296,6 +348,13 @@ namespace SpacetimeDB.Types
             /// Get this connection's <c>ConnectionId</c>.
             /// </summary>
             public ConnectionId ConnectionId => conn.ConnectionId;
+            /// <summary>
+            /// Register a callback to be called when a reducer with no handler returns an error.
+            /// </summary>
+            public event Action<ReducerEventContext, Exception>? OnUnhandledReducerError {
+                add => Reducers.InternalOnUnhandledReducerError += value;
+                remove => Reducers.InternalOnUnhandledReducerError -= value;
+            }
 
             internal SubscriptionEventContext(DbConnection conn)
             {
@@ -350,11 +409,11 @@ namespace SpacetimeDB.Types
 
             /// <summary>
             /// Subscribe to the following SQL queries.
-            /// 
+            ///
             /// This method returns immediately, with the data not yet added to the DbConnection.
             /// The provided callbacks will be invoked once the data is returned from the remote server.
             /// Data from all the provided queries will be returned at the same time.
-            /// 
+            ///
             /// See the SpacetimeDB SQL docs for more information on SQL syntax:
             /// <a href="https://spacetimedb.com/docs/sql">https://spacetimedb.com/docs/sql</a>
             /// </summary>
@@ -431,20 +490,41 @@ namespace SpacetimeDB.Types
         {
             var encodedArgs = update.ReducerCall.Args;
             return update.ReducerCall.ReducerName switch {
+                "advance_round" => BSATNHelpers.Decode<Reducer.AdvanceRound>(encodedArgs),
+                "boost" => BSATNHelpers.Decode<Reducer.Boost>(encodedArgs),
                 "change_cow_directions" => BSATNHelpers.Decode<Reducer.ChangeCowDirections>(encodedArgs),
-                "check_all_beams" => BSATNHelpers.Decode<Reducer.CheckAllBeams>(encodedArgs),
+                "close_arena" => BSATNHelpers.Decode<Reducer.CloseArena>(encodedArgs),
                 "connect" => BSATNHelpers.Decode<Reducer.Connect>(encodedArgs),
+                "create_arena" => BSATNHelpers.Decode<Reducer.CreateArena>(encodedArgs),
+                "define_achievement" => BSATNHelpers.Decode<Reducer.DefineAchievement>(encodedArgs),
                 "disconnect" => BSATNHelpers.Decode<Reducer.Disconnect>(encodedArgs),
                 "enter_game" => BSATNHelpers.Decode<Reducer.EnterGame>(encodedArgs),
+                "generate_map" => BSATNHelpers.Decode<Reducer.GenerateMap>(encodedArgs),
+                "join_arena" => BSATNHelpers.Decode<Reducer.JoinArena>(encodedArgs),
+                "join_queue" => BSATNHelpers.Decode<Reducer.JoinQueue>(encodedArgs),
+                "leave_arena" => BSATNHelpers.Decode<Reducer.LeaveArena>(encodedArgs),
+                "leave_queue" => BSATNHelpers.Decode<Reducer.LeaveQueue>(encodedArgs),
+                "load_embedded_map" => BSATNHelpers.Decode<Reducer.LoadEmbeddedMap>(encodedArgs),
                 "move_all_cows" => BSATNHelpers.Decode<Reducer.MoveAllCows>(encodedArgs),
-                "move_all_players" => BSATNHelpers.Decode<Reducer.MoveAllPlayers>(encodedArgs),
-                "process_abductions" => BSATNHelpers.Decode<Reducer.ProcessAbductions>(encodedArgs),
                 "process_game" => BSATNHelpers.Decode<Reducer.ProcessGame>(encodedArgs),
+                "relocate_control_zone" => BSATNHelpers.Decode<Reducer.RelocateControlZone>(encodedArgs),
+                "remove_achievement" => BSATNHelpers.Decode<Reducer.RemoveAchievement>(encodedArgs),
+                "run_matchmaker" => BSATNHelpers.Decode<Reducer.RunMatchmaker>(encodedArgs),
                 "send_message" => BSATNHelpers.Decode<Reducer.SendMessage>(encodedArgs),
+                "set_altitude_range" => BSATNHelpers.Decode<Reducer.SetAltitudeRange>(encodedArgs),
+                "set_bots" => BSATNHelpers.Decode<Reducer.SetBots>(encodedArgs),
+                "set_game_mode" => BSATNHelpers.Decode<Reducer.SetGameMode>(encodedArgs),
+                "set_mass_decay" => BSATNHelpers.Decode<Reducer.SetMassDecay>(encodedArgs),
                 "set_name" => BSATNHelpers.Decode<Reducer.SetName>(encodedArgs),
+                "set_teams" => BSATNHelpers.Decode<Reducer.SetTeams>(encodedArgs),
+                "shrink_safe_zone" => BSATNHelpers.Decode<Reducer.ShrinkSafeZone>(encodedArgs),
                 "spawn_cows" => BSATNHelpers.Decode<Reducer.SpawnCows>(encodedArgs),
+                "update_bots" => BSATNHelpers.Decode<Reducer.UpdateBots>(encodedArgs),
+                "update_leaderboards" => BSATNHelpers.Decode<Reducer.UpdateLeaderboards>(encodedArgs),
+                "update_player_altitude" => BSATNHelpers.Decode<Reducer.UpdatePlayerAltitude>(encodedArgs),
                 "update_player_beam" => BSATNHelpers.Decode<Reducer.UpdatePlayerBeam>(encodedArgs),
                 "update_player_input" => BSATNHelpers.Decode<Reducer.UpdatePlayerInput>(encodedArgs),
+                "upload_map" => BSATNHelpers.Decode<Reducer.UploadMap>(encodedArgs),
                 var reducer => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
         }
@@ -465,24 +545,50 @@ namespace SpacetimeDB.Types
         {
             var eventContext = (ReducerEventContext)context;
             return reducer switch {
+                Reducer.AdvanceRound args => Reducers.InvokeAdvanceRound(eventContext, args),
+                Reducer.Boost args => Reducers.InvokeBoost(eventContext, args),
                 Reducer.ChangeCowDirections args => Reducers.InvokeChangeCowDirections(eventContext, args),
-                Reducer.CheckAllBeams args => Reducers.InvokeCheckAllBeams(eventContext, args),
+                Reducer.CloseArena args => Reducers.InvokeCloseArena(eventContext, args),
                 Reducer.Connect args => Reducers.InvokeConnect(eventContext, args),
+                Reducer.CreateArena args => Reducers.InvokeCreateArena(eventContext, args),
+                Reducer.DefineAchievement args => Reducers.InvokeDefineAchievement(eventContext, args),
                 Reducer.Disconnect args => Reducers.InvokeDisconnect(eventContext, args),
                 Reducer.EnterGame args => Reducers.InvokeEnterGame(eventContext, args),
+                Reducer.GenerateMap args => Reducers.InvokeGenerateMap(eventContext, args),
+                Reducer.JoinArena args => Reducers.InvokeJoinArena(eventContext, args),
+                Reducer.JoinQueue args => Reducers.InvokeJoinQueue(eventContext, args),
+                Reducer.LeaveArena args => Reducers.InvokeLeaveArena(eventContext, args),
+                Reducer.LeaveQueue args => Reducers.InvokeLeaveQueue(eventContext, args),
+                Reducer.LoadEmbeddedMap args => Reducers.InvokeLoadEmbeddedMap(eventContext, args),
                 Reducer.MoveAllCows args => Reducers.InvokeMoveAllCows(eventContext, args),
-                Reducer.MoveAllPlayers args => Reducers.InvokeMoveAllPlayers(eventContext, args),
-                Reducer.ProcessAbductions args => Reducers.InvokeProcessAbductions(eventContext, args),
                 Reducer.ProcessGame args => Reducers.InvokeProcessGame(eventContext, args),
+                Reducer.RelocateControlZone args => Reducers.InvokeRelocateControlZone(eventContext, args),
+                Reducer.RemoveAchievement args => Reducers.InvokeRemoveAchievement(eventContext, args),
+                Reducer.RunMatchmaker args => Reducers.InvokeRunMatchmaker(eventContext, args),
                 Reducer.SendMessage args => Reducers.InvokeSendMessage(eventContext, args),
+                Reducer.SetAltitudeRange args => Reducers.InvokeSetAltitudeRange(eventContext, args),
+                Reducer.SetBots args => Reducers.InvokeSetBots(eventContext, args),
+                Reducer.SetGameMode args => Reducers.InvokeSetGameMode(eventContext, args),
+                Reducer.SetMassDecay args => Reducers.InvokeSetMassDecay(eventContext, args),
                 Reducer.SetName args => Reducers.InvokeSetName(eventContext, args),
+                Reducer.SetTeams args => Reducers.InvokeSetTeams(eventContext, args),
+                Reducer.ShrinkSafeZone args => Reducers.InvokeShrinkSafeZone(eventContext, args),
                 Reducer.SpawnCows args => Reducers.InvokeSpawnCows(eventContext, args),
+                Reducer.UpdateBots args => Reducers.InvokeUpdateBots(eventContext, args),
+                Reducer.UpdateLeaderboards args => Reducers.InvokeUpdateLeaderboards(eventContext, args),
+                Reducer.UpdatePlayerAltitude args => Reducers.InvokeUpdatePlayerAltitude(eventContext, args),
                 Reducer.UpdatePlayerBeam args => Reducers.InvokeUpdatePlayerBeam(eventContext, args),
                 Reducer.UpdatePlayerInput args => Reducers.InvokeUpdatePlayerInput(eventContext, args),
+                Reducer.UploadMap args => Reducers.InvokeUploadMap(eventContext, args),
                 _ => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
         }
 
         public SubscriptionBuilder SubscriptionBuilder() => new(this);
+        public event Action<ReducerEventContext, Exception> OnUnhandledReducerError
+        {
+            add => Reducers.InternalOnUnhandledReducerError += value;
+            remove => Reducers.InternalOnUnhandledReducerError -= value;
+        }
     }
 }
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -17,21 +19,21 @@ namespace SpacetimeDB.Types
         {
             protected override string RemoteTableName => "config";
 
-            public sealed class IdUniqueIndex : UniqueIndexBase<uint>
+            public sealed class ArenaIdUniqueIndex : UniqueIndexBase<uint>
             {
-                protected override uint GetKey(Config row) => row.Id;
+                protected override uint GetKey(Config row) => row.ArenaId;
 
-                public IdUniqueIndex(ConfigHandle table) : base(table) { }
+                public ArenaIdUniqueIndex(ConfigHandle table) : base(table) { }
             }
 
-            public readonly IdUniqueIndex Id;
+            public readonly ArenaIdUniqueIndex ArenaId;
 
             internal ConfigHandle(DbConnection conn) : base(conn)
             {
-                Id = new(this);
+                ArenaId = new(this);
             }
 
-            protected override object GetPrimaryKey(Config row) => row.Id;
+            protected override object GetPrimaryKey(Config row) => row.ArenaId;
         }
 
         public readonly ConfigHandle Config;
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -17,6 +19,15 @@ namespace SpacetimeDB.Types
         {
             protected override string RemoteTableName => "ufo";
 
+            public sealed class ArenaIdIndex : BTreeIndexBase<uint>
+            {
+                protected override uint GetKey(Ufo row) => row.ArenaId;
+
+                public ArenaIdIndex(UfoHandle table) : base(table) { }
+            }
+
+            public readonly ArenaIdIndex ArenaId;
+
             public sealed class EntityIdUniqueIndex : UniqueIndexBase<uint>
             {
                 protected override uint GetKey(Ufo row) => row.EntityId;
@@ -37,6 +48,7 @@ namespace SpacetimeDB.Types
 
             internal UfoHandle(DbConnection conn) : base(conn)
             {
+                ArenaId = new(this);
                 EntityId = new(this);
                 PlayerId = new(this);
             }
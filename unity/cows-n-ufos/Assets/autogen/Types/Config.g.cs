@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -13,22 +15,64 @@ namespace SpacetimeDB.Types
     [DataContract]
     public sealed partial class Config
     {
-        [DataMember(Name = "id")]
-        public uint Id;
-        [DataMember(Name = "world_size")]
-        public ulong WorldSize;
+        [DataMember(Name = "arena_id")]
+        public uint ArenaId;
+        [DataMember(Name = "mode")]
+        public GameMode Mode;
+        [DataMember(Name = "teams")]
+        public bool Teams;
+        [DataMember(Name = "bounds")]
+        public WorldBounds Bounds;
+        [DataMember(Name = "map_name")]
+        public string MapName;
+        [DataMember(Name = "map_seed")]
+        public ulong? MapSeed;
+        [DataMember(Name = "mass_decay_threshold")]
+        public uint MassDecayThreshold;
+        [DataMember(Name = "mass_decay_per_sec")]
+        public float MassDecayPerSec;
+        [DataMember(Name = "min_altitude")]
+        public float MinAltitude;
+        [DataMember(Name = "max_altitude")]
+        public float MaxAltitude;
+        [DataMember(Name = "bot_fill")]
+        public uint BotFill;
+        [DataMember(Name = "bot_difficulty")]
+        public BotDifficulty BotDifficulty;
 
         public Config(
-            uint Id,
-            ulong WorldSize
+            uint ArenaId,
+            GameMode Mode,
+            bool Teams,
+            WorldBounds Bounds,
+            string MapName,
+            ulong? MapSeed,
+            uint MassDecayThreshold,
+            float MassDecayPerSec,
+            float MinAltitude,
+            float MaxAltitude,
+            uint BotFill,
+            BotDifficulty BotDifficulty
         )
         {
-            this.Id = Id;
-            this.WorldSize = WorldSize;
+            this.ArenaId = ArenaId;
+            this.Mode = Mode;
+            this.Teams = Teams;
+            this.Bounds = Bounds;
+            this.MapName = MapName;
+            this.MapSeed = MapSeed;
+            this.MassDecayThreshold = MassDecayThreshold;
+            this.MassDecayPerSec = MassDecayPerSec;
+            this.MinAltitude = MinAltitude;
+            this.MaxAltitude = MaxAltitude;
+            this.BotFill = BotFill;
+            this.BotDifficulty = BotDifficulty;
         }
 
         public Config()
         {
+            this.Bounds = null!;
+            this.MapName = "";
         }
     }
 }
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -15,6 +17,10 @@ namespace SpacetimeDB.Types
     {
         [DataMember(Name = "entity_id")]
         public uint EntityId;
+        [DataMember(Name = "arena_id")]
+        public uint ArenaId;
+        [DataMember(Name = "kind")]
+        public CowKind Kind;
         [DataMember(Name = "direction")]
         public DbVector3 Direction;
         [DataMember(Name = "speed")]
@@ -23,25 +29,34 @@ namespace SpacetimeDB.Types
         public bool IsBeingAbducted;
         [DataMember(Name = "abducted_by")]
         public Entity? AbductedBy;
+        [DataMember(Name = "contested_by")]
+        public System.Collections.Generic.List<uint> ContestedBy;
 
         public Cow(
             uint EntityId,
+            uint ArenaId,
+            CowKind Kind,
             DbVector3 Direction,
             float Speed,
             bool IsBeingAbducted,
-            Entity? AbductedBy
+            Entity? AbductedBy,
+            System.Collections.Generic.List<uint> ContestedBy
         )
         {
             this.EntityId = EntityId;
+            this.ArenaId = ArenaId;
+            this.Kind = Kind;
             this.Direction = Direction;
             this.Speed = Speed;
             this.IsBeingAbducted = IsBeingAbducted;
             this.AbductedBy = AbductedBy;
+            this.ContestedBy = ContestedBy;
         }
 
         public Cow()
         {
             this.Direction = new();
+            this.ContestedBy = new();
         }
     }
 }
@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -15,6 +17,8 @@ namespace SpacetimeDB.Types
     {
         [DataMember(Name = "entity_id")]
         public uint EntityId;
+        [DataMember(Name = "arena_id")]
+        public uint ArenaId;
         [DataMember(Name = "position")]
         public DbVector3 Position;
         [DataMember(Name = "mass")]
@@ -22,11 +26,13 @@ namespace SpacetimeDB.Types
 
         public Entity(
             uint EntityId,
+            uint ArenaId,
             DbVector3 Position,
             uint Mass
         )
         {
             this.EntityId = EntityId;
+            this.ArenaId = ArenaId;
             this.Position = Position;
             this.Mass = Mass;
         }
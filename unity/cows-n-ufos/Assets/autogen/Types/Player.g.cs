@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -19,16 +21,28 @@ namespace SpacetimeDB.Types
         public uint PlayerId;
         [DataMember(Name = "name")]
         public string Name;
+        [DataMember(Name = "arena_id")]
+        public uint? ArenaId;
+        [DataMember(Name = "skill_rating")]
+        public uint SkillRating;
+        [DataMember(Name = "team_id")]
+        public uint? TeamId;
 
         public Player(
             SpacetimeDB.Identity Identity,
             uint PlayerId,
-            string Name
+            string Name,
+            uint? ArenaId,
+            uint SkillRating,
+            uint? TeamId
         )
         {
             this.Identity = Identity;
             this.PlayerId = PlayerId;
             this.Name = Name;
+            this.ArenaId = ArenaId;
+            this.SkillRating = SkillRating;
+            this.TeamId = TeamId;
         }
 
         public Player()
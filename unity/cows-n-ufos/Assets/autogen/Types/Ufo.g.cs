@@ -1,6 +1,8 @@
 // THIS FILE IS AUTOMATICALLY GENERATED BY SPACETIMEDB. EDITS TO THIS FILE
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
+// This was generated using spacetimedb cli version 1.3.2 (commit ).
+
 #nullable enable
 
 using System;
@@ -17,14 +19,26 @@ namespace SpacetimeDB.Types
         public uint EntityId;
         [DataMember(Name = "player_id")]
         public uint PlayerId;
+        [DataMember(Name = "arena_id")]
+        public uint ArenaId;
         [DataMember(Name = "direction")]
         public DbVector3 Direction;
         [DataMember(Name = "speed")]
         public float Speed;
+        [DataMember(Name = "velocity")]
+        public DbVector3 Velocity;
+        [DataMember(Name = "target_altitude")]
+        public float TargetAltitude;
         [DataMember(Name = "last_split_time")]
         public SpacetimeDB.Timestamp LastSplitTime;
+        [DataMember(Name = "last_boost_time")]
+        public SpacetimeDB.Timestamp LastBoostTime;
         [DataMember(Name = "beam_on")]
         public bool BeamOn;
+        [DataMember(Name = "beam_energy")]
+        public float BeamEnergy;
+        [DataMember(Name = "decay_remainder")]
+        public float DecayRemainder;
         [DataMember(Name = "abducting")]
         public bool Abducting;
         [DataMember(Name = "abducted_entity")]
@@ -33,20 +47,32 @@ namespace SpacetimeDB.Types
         public Ufo(
             uint EntityId,
             uint PlayerId,
+            uint ArenaId,
             DbVector3 Direction,
             float Speed,
+            DbVector3 Velocity,
+            float TargetAltitude,
             SpacetimeDB.Timestamp LastSplitTime,
+            SpacetimeDB.Timestamp LastBoostTime,
             bool BeamOn,
+            float BeamEnergy,
+            float DecayRemainder,
             bool Abducting,
             Entity? AbductedEntity
         )
         {
             this.EntityId = EntityId;
             this.PlayerId = PlayerId;
+            this.ArenaId = ArenaId;
             this.Direction = Direction;
             this.Speed = Speed;
+            this.Velocity = Velocity;
+            this.TargetAltitude = TargetAltitude;
             this.LastSplitTime = LastSplitTime;
+            this.LastBoostTime = LastBoostTime;
             this.BeamOn = BeamOn;
+            this.BeamEnergy = BeamEnergy;
+            this.DecayRemainder = DecayRemainder;
             this.Abducting = Abducting;
             this.AbductedEntity = AbductedEntity;
         }
@@ -54,6 +80,7 @@ namespace SpacetimeDB.Types
         public Ufo()
         {
             this.Direction = new();
+            this.Velocity = new();
         }
     }
 }